@@ -0,0 +1,115 @@
+use std::io::Write;
+
+use nil_slip44::Coin;
+
+const USAGE: &str = "usage: export-json [--output <path>]";
+
+fn main() {
+    let output = match parse_output(std::env::args().skip(1)) {
+        Ok(output) => output,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+    let result = match output {
+        Some(path) => std::fs::File::create(&path).and_then(|mut file| export(&mut file)),
+        None => export(&mut std::io::stdout().lock()),
+    };
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
+
+/// `--output` path, or `None` to write to stdout.
+fn parse_output(args: impl IntoIterator<Item = String>) -> Result<Option<String>, String> {
+    let mut output = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => match args.next() {
+                Some(path) => output = Some(path),
+                None => return Err(format!("--output needs a path\n{}", USAGE)),
+            },
+            other => return Err(format!("unknown argument {}\n{}", other, USAGE)),
+        }
+    }
+    Ok(output)
+}
+
+fn export(out: &mut impl Write) -> std::io::Result<()> {
+    let coins: Vec<_> = Coin::all()
+        .map(|coin| {
+            serde_json::json!({
+                "ids": coin.ids(),
+                "symbol": coin.raw_symbol(),
+                "name": coin.name(),
+                "coin_name": coin.coin_name(),
+            })
+        })
+        .collect();
+    serde_json::to_writer_pretty(&mut *out, &coins)?;
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut out = Vec::new();
+        export(&mut out).unwrap();
+        let coins: Vec<serde_json::Value> = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(coins.len(), Coin::all().count());
+        assert!(coins.contains(&serde_json::json!({
+            "ids": [0],
+            "symbol": "BTC",
+            "name": "Bitcoin",
+            "coin_name": "Bitcoin",
+        })));
+        assert!(coins.contains(&serde_json::json!({
+            "ids": [1],
+            "symbol": null,
+            "name": "Testnet",
+            "coin_name": "Testnet (all coins)",
+        })));
+    }
+
+    // CPChain is only in the full registry.
+    #[cfg(feature = "full")]
+    #[test]
+    fn full_registry() {
+        let mut out = Vec::new();
+        export(&mut out).unwrap();
+        let coins: Vec<serde_json::Value> = serde_json::from_slice(&out).unwrap();
+
+        assert!(coins.contains(&serde_json::json!({
+            "ids": [60],
+            "symbol": "ETH",
+            "name": "Ethereum",
+            "coin_name": "Ether",
+        })));
+        assert!(coins.contains(&serde_json::json!({
+            "ids": [337],
+            "symbol": "CPC",
+            "name": "CPChain",
+            "coin_name": "CPChain",
+        })));
+    }
+
+    #[test]
+    fn output() {
+        let parse = |args: &[&str]| parse_output(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(
+            parse(&["--output", "coins.json"]),
+            Ok(Some("coins.json".to_string()))
+        );
+        assert!(parse(&["--output"]).is_err());
+        assert!(parse(&["--pretty"]).is_err());
+    }
+}
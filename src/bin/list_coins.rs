@@ -0,0 +1,182 @@
+use std::io::Write;
+
+use nil_slip44::Coin;
+
+const USAGE: &str =
+    "usage: list-coins [--testnet] [--evm] [--symbol-only] [--format json|csv|table]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    Csv,
+    Table,
+}
+
+#[derive(Debug)]
+struct Options {
+    testnet: bool,
+    evm: bool,
+    symbol_only: bool,
+    format: Format,
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            testnet: false,
+            evm: false,
+            symbol_only: false,
+            format: Format::Table,
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--testnet" => options.testnet = true,
+                "--evm" => options.evm = true,
+                "--symbol-only" => options.symbol_only = true,
+                "--format" => {
+                    options.format = match args.next().as_deref() {
+                        Some("json") => Format::Json,
+                        Some("csv") => Format::Csv,
+                        Some("table") => Format::Table,
+                        Some(other) => return Err(format!("unknown format {}\n{}", other, USAGE)),
+                        None => return Err(format!("--format needs a value\n{}", USAGE)),
+                    }
+                }
+                other => return Err(format!("unknown argument {}\n{}", other, USAGE)),
+            }
+        }
+        Ok(options)
+    }
+
+    fn includes(&self, coin: Coin) -> bool {
+        let flags = coin.flags();
+        (!self.testnet || flags.is_testnet())
+            && (!self.evm || flags.is_evm())
+            && (!self.symbol_only || flags.has_symbol())
+    }
+}
+
+fn main() {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+    if let Err(error) = list(&options, &mut std::io::stdout().lock()) {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
+
+fn list(options: &Options, out: &mut impl Write) -> std::io::Result<()> {
+    let coins: Vec<Coin> = Coin::all().filter(|&coin| options.includes(coin)).collect();
+    match options.format {
+        Format::Json => {
+            writeln!(out, "[")?;
+            for (i, coin) in coins.iter().enumerate() {
+                let symbol = coin.raw_symbol().map_or("null".to_string(), json_string);
+                writeln!(
+                    out,
+                    "  {{\"id\": {}, \"symbol\": {}, \"name\": {}}}{}",
+                    coin.id(),
+                    symbol,
+                    json_string(coin.coin_name()),
+                    if i + 1 < coins.len() { "," } else { "" }
+                )?;
+            }
+            writeln!(out, "]")
+        }
+        Format::Csv => {
+            writeln!(out, "id,symbol,name")?;
+            for coin in &coins {
+                let symbol = coin.raw_symbol().unwrap_or("");
+                writeln!(
+                    out,
+                    "{},{},{}",
+                    coin.id(),
+                    csv_field(symbol),
+                    csv_field(coin.coin_name())
+                )?;
+            }
+            Ok(())
+        }
+        Format::Table => {
+            let sym_width = coins
+                .iter()
+                .filter_map(|coin| coin.symbol_len())
+                .max()
+                .unwrap_or(0);
+            for coin in &coins {
+                writeln!(out, "{}", coin.table_row(Coin::MAX_ID_DIGITS, sym_width))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// `s` as a quoted, escaped JSON string.
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> String {
+        let options = Options::parse(args.iter().map(|arg| arg.to_string())).unwrap();
+        let mut out = Vec::new();
+        list(&options, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn json_is_valid() {
+        let all: Vec<serde_json::Value> =
+            serde_json::from_str(&run(&["--format", "json"])).unwrap();
+        assert_eq!(all.len(), Coin::all().len());
+        assert!(all.iter().any(|coin| coin["symbol"].is_null()));
+    }
+
+    // The bitcoin-only registry has no EVM coins.
+    #[cfg(any(feature = "full", feature = "core-coins"))]
+    #[test]
+    fn evm_json() {
+        let coins: Vec<serde_json::Value> =
+            serde_json::from_str(&run(&["--evm", "--format", "json"])).unwrap();
+
+        assert!(!coins.is_empty());
+        assert!(coins.contains(&serde_json::json!({"id": 60, "symbol": "ETH", "name": "Ether"})));
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(
+            run(&["--testnet", "--format", "csv"]),
+            "id,symbol,name\n1,,Testnet (all coins)\n"
+        );
+        assert!(Options::parse(vec!["--format".to_string(), "xml".to_string()]).is_err());
+    }
+}
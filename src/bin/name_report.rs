@@ -0,0 +1,66 @@
+use std::io::Write;
+
+use nil_slip44::Coin;
+
+const USAGE: &str = "usage: name-report";
+
+fn main() {
+    if let Some(arg) = std::env::args().nth(1) {
+        eprintln!("unknown argument {}\n{}", arg, USAGE);
+        std::process::exit(2);
+    }
+    if let Err(error) = report(&mut std::io::stdout().lock()) {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
+
+/// Lists the coins whose variant name isn't their SLIP-0044 name, i.e. that `parse-coins` rewrote, in coin type order,
+/// one `<coin type>  <coin name> -> <variant name>` line each, then how many there are.
+fn report(out: &mut impl Write) -> std::io::Result<()> {
+    let renamed: Vec<Coin> = Coin::all()
+        .filter(|coin| coin.name() != coin.coin_name())
+        .collect();
+    for coin in &renamed {
+        writeln!(
+            out,
+            "{:>width$}  {} -> {}",
+            coin.id(),
+            coin.coin_name(),
+            coin.name(),
+            width = Coin::MAX_ID_DIGITS
+        )?;
+    }
+    writeln!(
+        out,
+        "{} of {} coins renamed",
+        renamed.len(),
+        Coin::all().len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renamed_coins() {
+        let mut out = Vec::new();
+        report(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+
+        let testnet = format!(
+            "{:>width$}  Testnet (all coins) -> Testnet",
+            1,
+            width = Coin::MAX_ID_DIGITS
+        );
+        assert!(lines.contains(&testnet.as_str()), "{}", out);
+        assert!(!out.contains("Bitcoin -> Bitcoin"));
+        let renamed = lines.len() - 1;
+        assert_eq!(
+            lines.last(),
+            Some(&format!("{} of {} coins renamed", renamed, Coin::all().len()).as_str())
+        );
+    }
+}
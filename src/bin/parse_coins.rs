@@ -1,312 +1,645 @@
-use std::collections::{HashMap, HashSet};
-use std::io::Write;
+use std::io::Read;
 use std::path::Path;
+use std::time::Duration;
 
-use itertools::Itertools;
-use reqwest;
-
+/// Raw SLIP-0044 markdown URL, `{}` being the revision, see `slip_0044_markdown_url`.
 const SLIP_0044_MARKDOWN_URL: &str =
-    "https://raw.githubusercontent.com/satoshilabs/slips/master/slip-0044.md";
-const SLIP_044_MARKDOWN_HEADER: &str =
-    "| Coin type  | Path component (`coin_type'`) | Symbol  | Coin                              |";
-
-#[derive(Debug)]
-struct CoinType {
-    id: u32,
-    ids: Vec<u32>,
-    path_component: String,
-    symbol: Option<String>,
-    name: String,
-    original_name: String,
-    rustdoc_lines: Vec<String>,
+    "https://raw.githubusercontent.com/satoshilabs/slips/{}/slip-0044.md";
+/// SLIP-0044 revision fetched from `SLIP_0044_MARKDOWN_URL` unless `--ref` is given, recorded in
+/// `REGISTRY_VERSION` and `SLIP44_SOURCE_REF`.
+const SLIP_0044_REF: &str = "master";
+/// Last markdown fetched from `SLIP_0044_MARKDOWN_URL` with its ETag, see `fetch_markdown`.
+const SLIP_0044_CACHE_PATH: &str = "target/slip-0044.cache";
+/// Environment variable that, when set, makes `fetch_markdown` use the cache without touching the network.
+const OFFLINE_VAR: &str = "SLIP44_OFFLINE";
+
+/// How `fetch_markdown` retries a failed fetch.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Retry {
+    /// `--attempts <n>`, fetches tried before giving up, at least 1.
+    attempts: u32,
+    /// `--timeout <seconds>` of each attempt.
+    timeout: Duration,
+    /// Wait before the first retry, doubled before each further one.
+    backoff: Duration,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("Fetching SLIP-0044 markdown from GitHub...");
-    let markdown_content = reqwest::blocking::get(SLIP_0044_MARKDOWN_URL)?.text()?;
-    println!(
-        "Successfully fetched {} bytes of markdown",
-        markdown_content.len()
-    );
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            timeout: Duration::from_secs(30),
+            backoff: Duration::from_secs(1),
+        }
+    }
+}
 
-    println!("Processing markdown content...");
-    let coin_types = markdown_content
-        .split("\n")
-        .skip_while(|&line| {
-            let skip = line != SLIP_044_MARKDOWN_HEADER;
-            if !skip {
-                println!("Found header line, starting processing...");
-            }
-            skip
-        })
-        .skip(2)
-        .filter_map(|line| {
-            let columns: Vec<_> = line.split('|').collect();
-            if columns.len() != 6 {
-                println!(
-                    "Warning: Skipping line due to incorrect number of columns: {}",
-                    line
-                );
-                return None;
-            }
+/// Where to read the SLIP-0044 markdown from.
+#[derive(Debug, PartialEq)]
+enum Source {
+    /// `SLIP_0044_MARKDOWN_URL` at the `--ref` revision, the default.
+    GitHub,
+    /// `--stdin`, or `--input -`.
+    Stdin,
+    /// `--input <path>`, e.g. a vendored copy of `slip-0044.md`.
+    File(String),
+}
 
-            let original_name = columns[4].trim();
-            if original_name.is_empty() || original_name == "reserved" {
-                println!(
-                    "Warning: Skipping coin due to empty or reserved name: {}",
-                    original_name
-                );
-                return None;
-            }
+/// Command line arguments of `parse-coins`.
+#[derive(Debug, PartialEq)]
+struct Args {
+    source: Source,
+    /// `--ref <git-sha-or-tag>`, the SLIP-0044 revision to fetch, or that a local copy was taken from.
+    git_ref: Option<String>,
+    /// `--strict`, fail instead of skipping coins that can't be parsed, see `assert_nothing_dropped`.
+    strict: bool,
+    /// `--verbose`, list every skipped row rather than only their counts, see `codegen::summarize`.
+    verbose: bool,
+    /// `--check`, compare the output with the committed `coin.rs` instead of writing it, see `check_generated`.
+    check: bool,
+    retry: Retry,
+}
 
-            let name = match original_name_to_short(original_name) {
-                Ok(n) => n,
-                Err(e) => {
-                    println!("Warning: Skipping coin due to name error: {}", e);
-                    return None;
+impl Args {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            source: Source::GitHub,
+            git_ref: None,
+            strict: false,
+            verbose: false,
+            check: false,
+            retry: Retry::default(),
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--stdin" => parsed.source = Source::Stdin,
+                "--input" => {
+                    parsed.source = match args.next().ok_or("--input needs a path")? {
+                        path if path == "-" => Source::Stdin,
+                        path => Source::File(path),
+                    }
                 }
-            };
-
-            let id = match columns[1].trim().parse::<u32>() {
-                Ok(id) => id,
-                Err(_) => {
-                    println!("Warning: Skipping coin due to invalid ID: {}", columns[1]);
-                    return None;
+                "--ref" => parsed.git_ref = Some(args.next().ok_or("--ref needs a revision")?),
+                "--strict" => parsed.strict = true,
+                "--verbose" => parsed.verbose = true,
+                "--check" => parsed.check = true,
+                "--attempts" => {
+                    parsed.retry.attempts = args
+                        .next()
+                        .and_then(|attempts| attempts.parse().ok())
+                        .filter(|attempts| *attempts > 0)
+                        .ok_or("--attempts needs a positive number")?
                 }
-            };
-
-            println!("Processing coin: {} (ID: {})", original_name, id);
-
-            Some(CoinType {
-                id,
-                ids: vec![],
-                path_component: columns[2].trim().to_string(),
-                symbol: Some(columns[3].trim())
-                    .map(prepend_enum)
-                    .map(|symbol| match symbol.as_str() {
-                        "$DAG" => "DAG".to_string(),
-                        symbol => symbol.to_string(),
-                    })
-                    .filter(|symbol| !symbol.is_empty()),
-                name: name.to_string(),
-                original_name: original_name.to_string(),
-                rustdoc_lines: vec![],
-            })
-        });
+                "--timeout" => {
+                    parsed.retry.timeout = args
+                        .next()
+                        .and_then(|seconds| seconds.parse().ok())
+                        .map(Duration::from_secs)
+                        .ok_or("--timeout needs a number of seconds")?
+                }
+                other => return Err(format!("unknown argument {}", other)),
+            }
+        }
+        Ok(parsed)
+    }
 
-    println!("Building coin type map...");
-    let coin_types = coin_types.fold(HashMap::<_, CoinType>::new(), |mut acc, coin_type| {
-        let id = coin_type.id.clone();
-        acc.entry((
-            coin_type.symbol.clone(),
-            coin_type.name.clone(),
-            coin_type.original_name.clone(),
-        ))
-        .or_insert(coin_type)
-        .ids
-        .push(id);
-        acc
-    });
-
-    println!("Processing {} unique coins...", coin_types.len());
-
-    let coin_types = coin_types
-        .into_iter()
-        .fold(HashMap::<_, Vec<_>>::new(), |mut acc, (_, coin_type)| {
-            acc.entry(coin_type.name.clone())
-                .or_default()
-                .push(coin_type);
-            acc
-        })
-        .into_iter()
-        .map(|(_, coin_types)| {
-            let coin_types = if coin_types.len() > 1 {
-                println!("Found duplicate coins for name: {}", coin_types[0].name);
-                coin_types
-                    .into_iter()
-                    .map(|coin_type| CoinType {
-                        name: format!(
-                            "{}_{}",
-                            coin_type.name.clone(),
-                            match coin_type.symbol.clone() {
-                                Some(symbol) => symbol,
-                                None => coin_type.ids.clone().into_iter().join("_").to_string(),
-                            }
-                        ),
-                        ..coin_type
-                    })
-                    .collect()
+    /// SLIP-0044 revision recorded in the generated `coin.rs`.
+    fn source_ref(&self) -> &str {
+        match (&self.git_ref, &self.source) {
+            (Some(git_ref), _) => git_ref,
+            (None, Source::GitHub) => SLIP_0044_REF,
+            (None, Source::Stdin) => "stdin",
+            (None, Source::File(_)) => "local",
+        }
+    }
+}
+
+fn slip_0044_markdown_url(git_ref: &str) -> String {
+    SLIP_0044_MARKDOWN_URL.replace("{}", git_ref)
+}
+
+/// Unix time recorded in the header of the generated `coin.rs`, taken from `SOURCE_DATE_EPOCH` if set for
+/// reproducible output, else `recorded` if given.
+fn generated_at(recorded: Option<u64>) -> Result<u64, Box<dyn std::error::Error>> {
+    match (std::env::var("SOURCE_DATE_EPOCH"), recorded) {
+        (Ok(epoch), _) => Ok(epoch.parse()?),
+        (Err(_), Some(recorded)) => Ok(recorded),
+        (Err(_), None) => Ok(std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs()),
+    }
+}
+
+/// Logs a step of the generation, as a `tracing` event with the `tracing` feature or printed to stdout otherwise.
+macro_rules! progress {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::info!($($arg)*);
+        #[cfg(not(feature = "tracing"))]
+        println!($($arg)*);
+    }};
+}
+
+/// Logs a skipped row or another oddity in the SLIP-0044 markdown, see `progress`.
+macro_rules! warning {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)*);
+        #[cfg(not(feature = "tracing"))]
+        println!("Warning: {}", format_args!($($arg)*));
+    }};
+}
+
+// Declared after `progress` and `warning`, which `codegen` logs with too.
+#[path = "parse_coins/codegen.rs"]
+mod codegen;
+
+/// Span of one phase of the generation, i.e. `fetch`, `parse` or `emit`, entered until dropped.
+#[cfg(feature = "tracing")]
+fn phase(name: &'static str) -> tracing::span::EnteredSpan {
+    tracing::info_span!("phase", name).entered()
+}
+
+/// Stand-in for the span of a phase without the `tracing` feature.
+#[cfg(not(feature = "tracing"))]
+struct Phase;
+
+#[cfg(not(feature = "tracing"))]
+fn phase(_name: &'static str) -> Phase {
+    Phase
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "tracing")]
+    tracing_subscriber::fmt::init();
+
+    let args = Args::parse(std::env::args().skip(1))?;
+    let source_ref = args.source_ref();
+    let markdown_content = {
+        let _fetch = phase("fetch");
+        let markdown_content = read_markdown(open_markdown(&args, source_ref)?)?;
+        progress!(
+            "Successfully fetched {} bytes of markdown",
+            markdown_content.len()
+        );
+        markdown_content
+    };
+    let coin_types = {
+        let _parse = phase("parse");
+        let (coin_types, warnings) = codegen::parse(&markdown_content)?;
+        if !warnings.is_empty() {
+            if args.verbose {
+                for warning in &warnings {
+                    warning!("{}", warning);
+                }
+                warning!("{}", codegen::summarize(&warnings));
             } else {
-                coin_types
-            };
-
-            coin_types
-                .into_iter()
-                .map(|coin_type| CoinType {
-                    rustdoc_lines: vec![
-                        format!("/// Coin type: {}", coin_type.ids.iter().join(", ")),
-                        if let Some(symbol) = coin_type.symbol.clone() {
-                            format!("/// Symbol: {}", symbol)
-                        } else {
-                            "".to_string()
-                        },
-                        format!("/// Coin: {}", coin_type.original_name),
-                    ],
-                    ..coin_type
-                })
-                .collect::<Vec<_>>()
-        })
-        .flatten();
+                warning!(
+                    "{}, pass --verbose to list them",
+                    codegen::summarize(&warnings)
+                );
+            }
+        }
+        if args.strict {
+            codegen::assert_nothing_dropped(&warnings)?;
+        }
+        codegen::assert_path_components(&coin_types)?;
+        progress!("Building coin type map...");
+        let coin_types = codegen::dedup(coin_types, &codegen::OVERRIDES);
+        codegen::assert_unique_primaries(&coin_types)?;
+        codegen::assert_valid_names(&coin_types)?;
+        progress!("Processing {} unique coins...", coin_types.len());
+        coin_types
+    };
+
+    let _emit = phase("emit");
 
-    println!("Creating output file...");
     let output_path = Path::new(file!())
         .parent()
         .ok_or("can't get first parent")?
         .parent()
         .ok_or("can't get second parent")?
         .join("coin.rs");
-    println!("Writing to: {}", output_path.display());
-
-    let mut file = std::fs::File::create(&output_path)?;
-
-    writeln!(&mut file, "// Code generated by {}; DO NOT EDIT.", file!())?;
-    writeln!(&mut file, "use crate::coins;")?;
-    writeln!(&mut file, "coins!(")?;
-
-    let mut seen_symbols = HashSet::<String>::new();
-    let mut coin_count = 0;
-
-    for coin_type in coin_types.sorted_by_key(|coin_type| coin_type.id) {
-        coin_count += 1;
-
-        // Pre-compute escaped symbol if it exists
-        let escaped_symbol = coin_type.symbol.as_ref().map(|s| escape_rust_string(s));
-
-        writeln!(
-            &mut file,
-            "    (\n        {}\n        [{}], {}, \"{}\", {}, {},\n    ),",
-            coin_type
-                .rustdoc_lines
-                .into_iter()
-                .filter(|s| !s.is_empty())
-                .join("\n        "),
-            coin_type.ids.into_iter().join(",").to_string(),
-            coin_type.name,
-            escape_rust_string(&coin_type.original_name),
-            match &escaped_symbol {
-                Some(symbol) => {
-                    if seen_symbols.contains(symbol) {
-                        ""
-                    } else {
-                        symbol
-                    }
-                }
-                None => "",
-            },
-            match &escaped_symbol {
-                Some(symbol) => {
-                    if seen_symbols.contains(symbol) {
-                        format!("\"{}\"", symbol)
-                    } else {
-                        seen_symbols.insert(symbol.clone());
-                        "".to_string()
-                    }
-                }
-                None => "".to_string(),
-            },
-        )?;
+    let committed = std::fs::read_to_string(&output_path).ok();
+    // `--check` keeps the committed generation time, so that only the registry itself is compared.
+    let recorded = committed
+        .as_deref()
+        .and_then(recorded_generated_at)
+        .filter(|_| args.check);
+    let generated = codegen::render(&coin_types, source_ref, generated_at(recorded)?)?;
+
+    if args.check {
+        progress!("Checking {} is up to date...", output_path.display());
+        if let Err(diff) = check_generated(committed.as_deref().unwrap_or(""), &generated) {
+            eprintln!(
+                "{} is stale, regenerate it:\n{}",
+                output_path.display(),
+                diff
+            );
+            std::process::exit(1);
+        }
+        progress!("{} is up to date", output_path.display());
+        return Ok(());
     }
-    writeln!(&mut file, ");")?;
 
-    println!(
+    progress!("Writing to: {}", output_path.display());
+    std::fs::write(&output_path, generated)?;
+
+    progress!(
         "Successfully wrote {} coins to {}",
-        coin_count,
+        coin_types.len(),
         output_path.display()
     );
-    println!("Done!");
+    progress!("Done!");
 
     Ok(())
 }
 
-fn parse_markdown_link(input: &str) -> (&str, Option<&str>) {
-    if input.starts_with('[') {
-        (
-            input.splitn(3, &['[', ']'][..]).nth(1).unwrap_or(input),
-            input
-                .trim_start_matches(']')
-                .splitn(3, &['(', ')'][..])
-                .nth(1),
-        )
-    } else {
-        (input, None)
+/// Generation time recorded in the header of a `coin_rs` written by `codegen::render`.
+fn recorded_generated_at(coin_rs: &str) -> Option<u64> {
+    let header = coin_rs.lines().nth(1)?;
+    let (_, generated_at) = header.rsplit_once("generated at Unix time ")?;
+    generated_at.strip_suffix('.')?.parse().ok()
+}
+
+/// Fails with a unified diff from `committed` to `generated` unless they're identical, whitespace included.
+fn check_generated(committed: &str, generated: &str) -> Result<(), String> {
+    if committed == generated {
+        return Ok(());
     }
+    Err(similar::TextDiff::from_lines(committed, generated)
+        .unified_diff()
+        .header("src/coin.rs", "generated")
+        .to_string())
 }
 
-fn original_name_to_short(original_name: &str) -> Result<String, String> {
-    let mut name = original_name.replace(' ', "");
-    name = name
-        .split_once('(')
-        .map_or(name.to_string(), |(name, _)| name.to_string());
-    name = prepend_enum(&name);
-
-    // Check direct mappings first
-    let name_match = match name.as_str() {
-        "Ether" => Ok("Ethereum"),
-        "EtherClassic" => Ok("EthereumClassic"),
-        name => Ok(name), // Default to original name if no mapping
-    };
+/// Markdown fetched from `url` before, with the ETag it was served with.
+#[derive(Debug, PartialEq)]
+struct Cache {
+    etag: String,
+    markdown: String,
+}
+
+impl Cache {
+    /// Cache of `url` stored at `path` as the URL, ETag and markdown, or `None` if there's none or it's unreadable
+    /// or for another URL.
+    fn read(path: &Path, url: &str) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let (cached_url, rest) = content.split_once('\n')?;
+        let (etag, markdown) = rest.split_once('\n')?;
+        (cached_url == url && !etag.is_empty()).then(|| Self {
+            etag: etag.to_string(),
+            markdown: markdown.to_string(),
+        })
+    }
+
+    fn write(&self, path: &Path, url: &str) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, format!("{}\n{}\n{}", url, self.etag, self.markdown))
+    }
+}
 
-    // Then handle special characters if needed
-    if name.contains(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_') {
-        let special_match = match name.as_str() {
-            "Pl^g" => Ok("Plug"),
-            "BitcoinMatteo'sVision" => Ok("BitcoinMatteosVision"),
-            "Crypto.orgChain" => Ok("CryptoOrgChain"),
-            "Cocos-BCX" => Ok("CocosBCX"),
-            "Capricoin+" => Ok("CapricoinPlus"),
-            "Seele-N" => Ok("SeeleN"),
-            "IQ-Cash" => Ok("IQCash"),
-            "XinFin.Network" => Ok("XinFinNetwork"),
-            "Unit-e" => Ok("UnitE"),
-            "HARMONY-ONE" => Ok("HarmonyOne"),
-            "ThePower.io" => Ok("ThePower"),
-            "evan.network" => Ok("EvanNetwork"),
-            "Ether-1" => Ok("EtherOne"),
-            "æternity" => Ok("aeternity"),
-            "θ" => Ok("Theta"),
-            name => name_match.and_then(|_| Err(format!("unknown original coin name `{}`", name))),
+/// Fetches the markdown at `url`, sending the ETag cached at `cache_path` so that a `304 Not Modified` reuses the
+/// cached markdown instead of downloading it again.
+///
+/// A missing or corrupt cache falls back to a full fetch. With `offline` the cache is used without touching the
+/// network, failing if there's none. Failed fetches, e.g. timeouts or error responses, are retried per `retry`.
+fn fetch_markdown(
+    url: &str,
+    cache_path: &Path,
+    offline: bool,
+    retry: Retry,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let cache = Cache::read(cache_path, url);
+    if offline {
+        progress!("{} is set, using {}", OFFLINE_VAR, cache_path.display());
+        return match cache {
+            Some(cache) => Ok(cache.markdown),
+            None => Err(format!(
+                "{} is set but {} has no cache of {}",
+                OFFLINE_VAR,
+                cache_path.display(),
+                url
+            )
+            .into()),
         };
-        special_match.map(|name| name.to_string())
-    } else {
-        name_match.map(|name| name.to_string())
     }
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(retry.timeout)
+        .build()?;
+    // `None` if not modified since the cached fetch.
+    let fetched = with_retries(retry, || {
+        let mut request = client.get(url);
+        if let Some(cache) = &cache {
+            request = request.header(reqwest::header::IF_NONE_MATCH, &cache.etag);
+        }
+        let response = request.send()?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED && cache.is_some() {
+            return Ok(None);
+        }
+
+        let response = response.error_for_status()?;
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        Ok(Some((etag, response.text()?)))
+    })?;
+    let (etag, markdown) = match (fetched, cache) {
+        (Some(fetched), _) => fetched,
+        (None, cache) => {
+            progress!(
+                "Not modified since last fetch, using {}",
+                cache_path.display()
+            );
+            return Ok(cache.ok_or("not modified without a cache")?.markdown);
+        }
+    };
+    if let Some(etag) = etag {
+        let cache = Cache { etag, markdown };
+        if let Err(e) = cache.write(cache_path, url) {
+            warning!("Can't cache markdown in {}: {}", cache_path.display(), e);
+        }
+        return Ok(cache.markdown);
+    }
+    Ok(markdown)
 }
 
-fn prepend_enum(name: &str) -> String {
-    if name.starts_with(char::is_numeric) {
-        ["_", name].join("")
-    } else {
-        name.to_string()
+/// Calls `fetch` until it succeeds, at most `retry.attempts` times, waiting `retry.backoff` before the first retry
+/// and twice as long before each further one. Fails with the last error once all attempts failed.
+fn with_retries<T>(
+    retry: Retry,
+    mut fetch: impl FnMut() -> Result<T, Box<dyn std::error::Error>>,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let mut backoff = retry.backoff;
+    let mut attempt = 1;
+    loop {
+        match fetch() {
+            Ok(fetched) => return Ok(fetched),
+            Err(e) if attempt < retry.attempts => {
+                warning!(
+                    "Attempt {} of {} failed, retrying in {:?}: {}",
+                    attempt,
+                    retry.attempts,
+                    backoff,
+                    e
+                );
+                std::thread::sleep(backoff);
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(e) => return Err(format!("fetch failed after {} attempts: {}", attempt, e).into()),
+        }
     }
 }
 
-fn escape_rust_string(s: &str) -> String {
-    s.replace('@', "") // Remove @ symbols
-        .replace('^', "") // Remove ^ symbols
-        .replace('\'', "") // Remove single quotes
-        .replace('"', "") // Remove double quotes
-        .replace('\\', "") // Remove backslashes
-        .replace('$', "") // Remove dollar signs
-        .chars()
-        .filter(|c| {
-            c.is_ascii_alphanumeric()
-                || *c == '_'
-                || *c == ' '
-                || *c == '-'
-                || *c == '+'
-                || *c == '.'
-                || *c == '('
-                || *c == ')'
-        }) // Only allow these characters
-        .collect()
+/// Reader over the markdown of `args.source`. The GitHub markdown is fetched, or taken from the cache, as a whole
+/// first, see `fetch_markdown`.
+fn open_markdown(
+    args: &Args,
+    source_ref: &str,
+) -> Result<Box<dyn Read>, Box<dyn std::error::Error>> {
+    Ok(match &args.source {
+        Source::Stdin => {
+            progress!("Reading SLIP-0044 markdown from stdin...");
+            Box::new(std::io::stdin().lock())
+        }
+        Source::File(path) => {
+            progress!("Reading SLIP-0044 markdown from {}...", path);
+            Box::new(std::fs::File::open(path).map_err(|e| format!("can't open {}: {}", path, e))?)
+        }
+        Source::GitHub => {
+            let url = slip_0044_markdown_url(source_ref);
+            progress!("Fetching SLIP-0044 markdown from {}...", url);
+            let offline = std::env::var_os(OFFLINE_VAR).is_some();
+            let markdown =
+                fetch_markdown(&url, Path::new(SLIP_0044_CACHE_PATH), offline, args.retry)?;
+            Box::new(std::io::Cursor::new(markdown))
+        }
+    })
+}
+
+/// Markdown read from `reader`, which fails unless it's UTF-8.
+fn read_markdown(mut reader: impl Read) -> std::io::Result<String> {
+    let mut markdown = String::new();
+    reader.read_to_string(&mut markdown)?;
+    Ok(markdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args() {
+        let parse = |args: &[&str]| Args::parse(args.iter().map(|arg| arg.to_string()));
+
+        let args = parse(&[]).unwrap();
+        assert_eq!(args.source, Source::GitHub);
+        assert_eq!(args.source_ref(), "master");
+        assert_eq!(parse(&["--stdin"]).unwrap().source_ref(), "stdin");
+
+        let args = parse(&["--input", "slip-0044.md"]).unwrap();
+        assert_eq!(args.source, Source::File("slip-0044.md".to_string()));
+        assert_eq!(args.source_ref(), "local");
+
+        let args = parse(&["--ref", "v1.2", "--input", "slip-0044.md"]).unwrap();
+        assert_eq!(args.source, Source::File("slip-0044.md".to_string()));
+        assert_eq!(args.source_ref(), "v1.2");
+
+        assert!(parse(&["--input"]).is_err());
+        assert_eq!(parse(&["--input", "-"]).unwrap().source, Source::Stdin);
+        assert_eq!(parse(&["--input", "-"]).unwrap().source_ref(), "stdin");
+        assert!(parse(&["--ref"]).is_err());
+        assert!(!args.strict);
+        assert!(parse(&["--strict"]).unwrap().strict);
+        assert!(!args.verbose);
+        assert!(parse(&["--verbose"]).unwrap().verbose);
+        assert!(!args.check);
+        assert!(
+            parse(&["--check", "--input", "slip-0044.md"])
+                .unwrap()
+                .check
+        );
+        assert!(parse(&["--offline"]).is_err());
+
+        assert_eq!(args.retry, Retry::default());
+        let retry = parse(&["--attempts", "5", "--timeout", "10"])
+            .unwrap()
+            .retry;
+        assert_eq!(retry.attempts, 5);
+        assert_eq!(retry.timeout, Duration::from_secs(10));
+        assert!(parse(&["--attempts", "0"]).is_err());
+        assert!(parse(&["--timeout", "soon"]).is_err());
+    }
+
+    #[test]
+    fn retries() {
+        let retry = Retry {
+            attempts: 3,
+            backoff: Duration::ZERO,
+            ..Retry::default()
+        };
+
+        let mut calls = 0;
+        let err = with_retries(retry, || -> Result<(), _> {
+            calls += 1;
+            Err(format!("timed out {}", calls).into())
+        })
+        .unwrap_err();
+        assert_eq!(calls, 3);
+        assert_eq!(
+            err.to_string(),
+            "fetch failed after 3 attempts: timed out 3"
+        );
+
+        let mut calls = 0;
+        let fetched = with_retries(retry, || {
+            calls += 1;
+            if calls < 2 {
+                return Err("connection reset".into());
+            }
+            Ok(calls)
+        });
+        assert_eq!(fetched.unwrap(), 2);
+    }
+
+    #[test]
+    fn offline_cache() {
+        let url = slip_0044_markdown_url("cached");
+        let cache_path = std::env::temp_dir()
+            .join(format!("parse-coins-{}", std::process::id()))
+            .join("slip-0044.cache");
+        let markdown = [
+            codegen::SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | BTC     | Bitcoin                           |",
+        ]
+        .join("\n");
+
+        assert!(fetch_markdown(&url, &cache_path, true, Retry::default()).is_err());
+
+        let cache = Cache {
+            etag: "\"abc\"".to_string(),
+            markdown,
+        };
+        cache.write(&cache_path, &url).unwrap();
+        assert_eq!(Cache::read(&cache_path, &url).as_ref(), Some(&cache));
+        assert_eq!(
+            Cache::read(&cache_path, "https://example.com/slip-0044.md"),
+            None
+        );
+
+        let fetched = fetch_markdown(&url, &cache_path, true, Retry::default()).unwrap();
+        assert_eq!(codegen::parse(&fetched).unwrap().0.len(), 1);
+
+        std::fs::write(&cache_path, "corrupt").unwrap();
+        assert_eq!(Cache::read(&cache_path, &url), None);
+        assert!(fetch_markdown(&url, &cache_path, true, Retry::default()).is_err());
+
+        std::fs::remove_dir_all(cache_path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn check() {
+        let header = "// Code generated by src/bin/parse_coins.rs; DO NOT EDIT.\n\
+             // SLIP-0044 source: master, generated at Unix time 1700000000.\n";
+        assert_eq!(recorded_generated_at(header), Some(1700000000));
+        assert_eq!(recorded_generated_at("// Code generated\n"), None);
+
+        let committed = format!("{}coins!(\n    Bitcoin,\n);\n", header);
+        assert_eq!(check_generated(&committed, &committed), Ok(()));
+        let diff = check_generated(
+            &committed,
+            &committed.replace("    Bitcoin", "    Bitcoin "),
+        )
+        .unwrap_err();
+        assert!(
+            diff.contains("--- src/coin.rs\n+++ generated\n"),
+            "{}",
+            diff
+        );
+        assert!(
+            diff.contains("\n-    Bitcoin,\n+    Bitcoin ,\n"),
+            "{}",
+            diff
+        );
+    }
+
+    /// Regenerates `coin.rs` from the SLIP-0044 markdown at `SLIP44_MARKDOWN`, e.g. a vendored copy of the revision
+    /// it was generated from, and compares it with the committed one. Skipped if `SLIP44_MARKDOWN` isn't set.
+    #[test]
+    fn coin_rs_is_up_to_date() {
+        let Some(markdown_path) = std::env::var_os("SLIP44_MARKDOWN") else {
+            return;
+        };
+        let markdown = std::fs::read_to_string(markdown_path).unwrap();
+        let committed = include_str!("../coin.rs");
+        let source_ref = committed
+            .lines()
+            .nth(1)
+            .and_then(|header| header.strip_prefix("// SLIP-0044 source: "))
+            .and_then(|header| header.split_once(','))
+            .map(|(source_ref, _)| source_ref)
+            .unwrap();
+
+        let coin_types = codegen::dedup(codegen::parse(&markdown).unwrap().0, &codegen::OVERRIDES);
+        let recorded = recorded_generated_at(committed).unwrap();
+        let generated = codegen::render(&coin_types, source_ref, recorded).unwrap();
+        if let Err(diff) = check_generated(committed, &generated) {
+            panic!("src/coin.rs is stale:\n{}", diff);
+        }
+    }
+
+    #[test]
+    fn markdown_url() {
+        assert_eq!(
+            slip_0044_markdown_url("0123abc"),
+            "https://raw.githubusercontent.com/satoshilabs/slips/0123abc/slip-0044.md"
+        );
+    }
+
+    #[test]
+    fn read_markdown_from_stdin_bytes() {
+        let fixture = format!(
+            "{}\n|------------|-------------------------------|---------|-----------------------------------|\n| 0          | 0x80000000                    | BTC     | Bitcoin                           |\n",
+            codegen::SLIP_044_MARKDOWN_HEADER
+        );
+
+        assert_eq!(read_markdown(fixture.as_bytes()).unwrap(), fixture);
+        assert!(read_markdown(&[0xff, 0xfe][..]).is_err());
+    }
+
+    #[test]
+    fn parse_from_reader() {
+        let fixture = format!(
+            "{}\n|------------|-------------------------------|---------|-----------------------------------|\n| 0          | 0x80000000                    | BTC     | [Bitcoin](https://bitcoin.org/)   |\n| 2          | 0x80000002                    | LTC     | Litecoin                          |\n",
+            codegen::SLIP_044_MARKDOWN_HEADER
+        );
+        let reader: Box<dyn Read> = Box::new(fixture.as_bytes());
+
+        let (coin_types, warnings) = codegen::parse(&read_markdown(reader).unwrap()).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        let coin_types: Vec<String> = coin_types
+            .iter()
+            .map(|coin_type| format!("{:?}", coin_type))
+            .collect();
+        assert_eq!(coin_types.len(), 2);
+        assert!(
+            coin_types[0].contains("name: \"Bitcoin\""),
+            "{}",
+            coin_types[0]
+        );
+        assert!(coin_types[0].contains("homepage: Some(\"https://bitcoin.org/\")"));
+        assert!(
+            coin_types[1].contains("symbol: Some(\"LTC\")"),
+            "{}",
+            coin_types[1]
+        );
+    }
 }
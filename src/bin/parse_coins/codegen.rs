@@ -0,0 +1,1374 @@
+//! Stages of `parse-coins` turning the SLIP-0044 markdown into `coin.rs`: `parse` the table, `dedup` its rows into
+//! coins and `render` them, each a function of its input only, logging aside.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+/// Generator named in the header of `coin.rs`, see `render`.
+const GENERATOR: &str = "src/bin/parse_coins.rs";
+/// Bumped whenever the generated registry changes for reasons other than the SLIP-0044 source,
+/// e.g. new naming rules, so that `REGISTRY_VERSION` changes with it.
+const REGISTRY_EPOCH: u32 = 1;
+/// Header row of the SLIP-0044 table at the time of writing, for test fixtures. `parse` finds the columns by name, see
+/// `Columns`.
+#[cfg(test)]
+pub(crate) const SLIP_044_MARKDOWN_HEADER: &str =
+    "| Coin type  | Path component (`coin_type'`) | Symbol  | Coin                              |";
+
+/// Coin type of Bitcoin, the only coin besides the testnet built with `bitcoin-only`, see `is_bitcoin_only`.
+const BITCOIN_COIN_TYPE: u32 = 0;
+/// Bit set in hardened derivation path components, see `assert_path_components`.
+const HARDENED: u32 = 0x8000_0000;
+/// Coin type SLIP-0044 shares between the testnets of all coins, see `assert_unique_primaries`.
+const TESTNET_COIN_TYPE: u32 = 1;
+/// Coin types returned by the generated `Coin::common()`, most popular first.
+const COMMON_COIN_TYPES: &[u32] = &[
+    0, 60, 714, 144, 501, 195, 3, 1815, 145, 3030, 148, 9000, 784, 2, 354, 128, 607, 397, 637, 61,
+    118, 966, 9001, 461, 283, 614, 5757, 1729, 133, 5, 194, 434,
+];
+
+/// Coin of the SLIP-0044 table, or once merged by `dedup`, all rows of one coin.
+#[derive(Debug)]
+pub(crate) struct CoinType {
+    id: u32,
+    ids: Vec<u32>,
+    /// SLIP-0044 `coin_type'` column, checked by `assert_path_components`.
+    path_component: String,
+    symbol: Option<String>,
+    name: String,
+    original_name: String,
+    /// URL the SLIP-0044 coin name links to, if any.
+    homepage: Option<String>,
+}
+
+/// `coin.rs` for `coin_types`, i.e. the full registry, the trimmed one of `is_core` coins and the minimal one of
+/// `is_bitcoin_only` coins.
+///
+/// Fails if one of `COMMON_COIN_TYPES` isn't among `coin_types`.
+pub(crate) fn render(
+    coin_types: &[CoinType],
+    source_ref: &str,
+    generated_at: u64,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut out = String::new();
+    writeln!(out, "// Code generated by {}; DO NOT EDIT.", GENERATOR)?;
+    writeln!(
+        out,
+        "// SLIP-0044 source: {}, generated at Unix time {}.",
+        source_ref, generated_at
+    )?;
+    writeln!(out, "use crate::coins;")?;
+
+    let coin_types: Vec<_> = coin_types
+        .iter()
+        .sorted_by_key(|coin_type| coin_type.id)
+        .collect();
+    let common_coins = COMMON_COIN_TYPES
+        .iter()
+        .map(|id| {
+            coin_types
+                .iter()
+                .find(|coin_type| coin_type.ids.contains(id))
+                .map(|coin_type| coin_type.name.as_str())
+                .ok_or_else(|| format!("common coin type {} is not in SLIP-0044", id))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let core_coin_types: Vec<_> = coin_types
+        .iter()
+        .copied()
+        .filter(|coin_type| is_core(coin_type))
+        .collect();
+    let bitcoin_coin_types: Vec<_> = coin_types
+        .iter()
+        .copied()
+        .filter(|coin_type| is_bitcoin_only(coin_type))
+        .collect();
+    let bitcoin_common_coins: Vec<_> = common_coins
+        .iter()
+        .copied()
+        .filter(|name| {
+            bitcoin_coin_types
+                .iter()
+                .any(|coin_type| coin_type.name == *name)
+        })
+        .collect();
+
+    render_registry(&mut out, "feature = \"full\"", &coin_types, &common_coins)?;
+    render_registry(
+        &mut out,
+        "all(not(feature = \"full\"), feature = \"core-coins\")",
+        &core_coin_types,
+        &common_coins,
+    )?;
+    render_registry(
+        &mut out,
+        "not(any(feature = \"full\", feature = \"core-coins\"))",
+        &bitcoin_coin_types,
+        &bitcoin_common_coins,
+    )?;
+    writeln!(
+        out,
+        "/// Version of the registry, `<epoch>+<SLIP-0044 revision>`. See [crate::is_compatible_with]."
+    )?;
+    writeln!(
+        out,
+        "pub const REGISTRY_VERSION: &str = \"{}+{}\";",
+        REGISTRY_EPOCH, source_ref
+    )?;
+    writeln!(
+        out,
+        "/// SLIP-0044 revision the registry was generated from, e.g. a commit SHA, `master` for the latest one at the time"
+    )?;
+    writeln!(
+        out,
+        "/// or `stdin`/`local` for markdown not fetched by `parse-coins`."
+    )?;
+    writeln!(
+        out,
+        "pub const SLIP44_SOURCE_REF: &str = \"{}\";",
+        source_ref
+    )?;
+
+    Ok(out)
+}
+
+/// Whether `coin_type` is in the trimmed registry built with `core-coins` but not `full`, i.e. one of
+/// `COMMON_COIN_TYPES` or the testnet.
+fn is_core(coin_type: &CoinType) -> bool {
+    coin_type
+        .ids
+        .iter()
+        .any(|id| *id == TESTNET_COIN_TYPE || COMMON_COIN_TYPES.contains(id))
+}
+
+/// Whether `coin_type` is in the minimal registry built with `bitcoin-only` but neither `full` nor `core-coins`,
+/// i.e. Bitcoin or the testnet.
+fn is_bitcoin_only(coin_type: &CoinType) -> bool {
+    coin_type
+        .ids
+        .iter()
+        .any(|id| *id == BITCOIN_COIN_TYPE || *id == TESTNET_COIN_TYPE)
+}
+
+/// Owner of each symbol of `coin_types`, by primary coin type: the coin with the lowest one, whatever the order of
+/// `coin_types`.
+///
+/// Only the owner's symbol is linked to `Symbol`, the other coins sharing it get it as a duplicate symbol instead,
+/// i.e. `Coin::symbol` returns `None` for them while `Coin::raw_symbol` still returns it.
+fn symbol_owners<'a>(coin_types: &[&'a CoinType]) -> HashMap<&'a str, u32> {
+    let mut owners = HashMap::<&str, u32>::new();
+    for coin_type in coin_types {
+        if let Some(symbol) = &coin_type.symbol {
+            owners
+                .entry(symbol)
+                .and_modify(|owner| *owner = (*owner).min(coin_type.id))
+                .or_insert(coin_type.id);
+        }
+    }
+    owners
+}
+
+/// Writes the `coins!` invocation for `coin_types`, sorted by coin type, and the `impl Coin` block and `COIN_COUNT`
+/// derived from them to `out`, all only compiled with `cfg`.
+fn render_registry(
+    out: &mut String,
+    cfg: &str,
+    coin_types: &[&CoinType],
+    common_coins: &[&str],
+) -> std::fmt::Result {
+    writeln!(out, "#[cfg({})]", cfg)?;
+    writeln!(out, "coins!(")?;
+
+    let owners = symbol_owners(coin_types);
+    let mut max_coin_type = 0;
+
+    for coin_type in coin_types {
+        max_coin_type = coin_type.ids.iter().copied().fold(max_coin_type, u32::max);
+
+        let duplicate_symbol = match &coin_type.symbol {
+            Some(symbol) => owners[symbol.as_str()] != coin_type.id,
+            None => false,
+        };
+        write!(
+            out,
+            "{}",
+            format_macro_entry(
+                &coin_type.ids,
+                &coin_type.name,
+                &coin_type.original_name,
+                coin_type.symbol.as_deref(),
+                duplicate_symbol,
+                coin_type.homepage.as_deref(),
+            )
+        )?;
+    }
+    writeln!(out, ");")?;
+    writeln!(out, "#[cfg({})]", cfg)?;
+    writeln!(out, "impl Coin {{")?;
+    writeln!(out, "    /// Largest coin type in the registry.")?;
+    writeln!(out, "    pub const MAX_COIN_TYPE: u32 = {};", max_coin_type)?;
+    writeln!(
+        out,
+        "    /// Number of decimal digits in [Coin::MAX_COIN_TYPE], for aligning coin type columns."
+    )?;
+    writeln!(
+        out,
+        "    pub const MAX_ID_DIGITS: usize = {};",
+        max_coin_type.to_string().len()
+    )?;
+    writeln!(
+        out,
+        "    /// Curated list of popular coins, most popular first."
+    )?;
+    writeln!(out, "    #[cfg(feature = \"alloc\")]")?;
+    writeln!(out, "    pub fn common() -> Vec<Coin> {{")?;
+    // As `rustfmt` would write it, so that formatting the crate leaves `coin.rs` as generated.
+    if let [name] = common_coins {
+        writeln!(out, "        vec![Coin::{}]", name)?;
+    } else {
+        writeln!(out, "        vec![")?;
+        for name in common_coins {
+            writeln!(out, "            Coin::{},", name)?;
+        }
+        writeln!(out, "        ]")?;
+    }
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(
+        out,
+        "/// Number of coins in the registry, i.e. of [Coin] variants."
+    )?;
+    writeln!(out, "#[cfg({})]", cfg)?;
+    writeln!(out, "pub const COIN_COUNT: usize = {};", coin_types.len())?;
+
+    Ok(())
+}
+
+/// Row of the SLIP-0044 table that `parse` skipped, and why.
+#[derive(Debug, PartialEq)]
+pub(crate) enum ParseWarning {
+    /// Non-blank line that isn't a table row of 4 columns, e.g. text after the table.
+    BadColumnCount(String),
+    /// Name of a row that's `reserved` or empty, which has no coin.
+    ReservedOrEmpty(String),
+    /// Name `original_name_to_short` can't map to a variant name, with why.
+    NameError { name: String, error: String },
+    /// Name of a coin with an unparsable ID, with the ID.
+    BadId { name: String, id: String },
+}
+
+impl ParseWarning {
+    /// Whether a coin was dropped from the registry, i.e. the row wasn't just reserved or no row at all.
+    fn drops_coin(&self) -> bool {
+        matches!(self, Self::NameError { .. } | Self::BadId { .. })
+    }
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BadColumnCount(line) => {
+                write!(f, "line with incorrect number of columns: {}", line)
+            }
+            Self::ReservedOrEmpty(name) => write!(f, "empty or reserved name: {}", name),
+            Self::NameError { name, error } => write!(f, "{}: {}", name, error),
+            Self::BadId { name, id } => write!(f, "{}: invalid ID `{}`", name, id),
+        }
+    }
+}
+
+/// Counts of `warnings` per `ParseWarning` variant, in one line.
+pub(crate) fn summarize(warnings: &[ParseWarning]) -> String {
+    let count = |matches: fn(&ParseWarning) -> bool| warnings.iter().filter(|w| matches(w)).count();
+    format!(
+        "skipped {} rows: {} with a bad column count, {} reserved or empty, {} with a name error, {} with a bad ID",
+        warnings.len(),
+        count(|w| matches!(w, ParseWarning::BadColumnCount(_))),
+        count(|w| matches!(w, ParseWarning::ReservedOrEmpty(_))),
+        count(|w| matches!(w, ParseWarning::NameError { .. })),
+        count(|w| matches!(w, ParseWarning::BadId { .. })),
+    )
+}
+
+/// Positions of the SLIP-0044 table columns `parse` reads, in the cells of a row split at `|`, found by name in the
+/// header row so that reordered or added columns don't mix up the values.
+#[derive(Debug, PartialEq)]
+struct Columns {
+    /// Cells per row, including the empty ones before the first and after the last `|`.
+    count: usize,
+    /// `Coin type` column.
+    id: usize,
+    /// `Path component` column, whose name ends with an example like ``(`coin_type'`)``.
+    path_component: usize,
+    /// `Symbol` column.
+    symbol: usize,
+    /// `Coin` column.
+    name: usize,
+}
+
+impl Columns {
+    /// Whether `line` is the header row of the SLIP-0044 table.
+    fn is_header(line: &str) -> bool {
+        line.starts_with('|') && line.split('|').any(|cell| cell.trim() == "Coin type")
+    }
+
+    /// Columns of the table with the header row `header`, failing if one of them is missing.
+    fn from_header(header: &str) -> Result<Self, String> {
+        let cells: Vec<_> = header.split('|').map(str::trim).collect();
+        let find = |name: &str, matches: fn(&str) -> bool| {
+            cells
+                .iter()
+                .position(|cell| matches(cell))
+                .ok_or_else(|| format!("SLIP-0044 table has no `{}` column: {}", name, header))
+        };
+        Ok(Self {
+            count: cells.len(),
+            id: find("Coin type", |cell| cell == "Coin type")?,
+            path_component: find("Path component", |cell| cell.starts_with("Path component"))?,
+            symbol: find("Symbol", |cell| cell == "Symbol")?,
+            name: find("Coin", |cell| cell == "Coin")?,
+        })
+    }
+}
+
+/// Coins of the SLIP-0044 table in `markdown`, one per row, skipping reserved and malformed rows.
+///
+/// Also returns a `ParseWarning` for each skipped row, instead of logging them one by one, see `summarize`. Fails if
+/// `markdown` has no table, or its header lacks a column, see `Columns`.
+pub(crate) fn parse(markdown: &str) -> Result<(Vec<CoinType>, Vec<ParseWarning>), String> {
+    progress!("Processing markdown content...");
+    let mut lines = markdown.split('\n');
+    let header = lines
+        .by_ref()
+        .find(|line| Columns::is_header(line))
+        .ok_or("SLIP-0044 markdown has no table with a `Coin type` column")?;
+    progress!("Found header line, starting processing...");
+    let columns = Columns::from_header(header)?;
+
+    let mut warnings = Vec::new();
+    let coin_types: Vec<_> = lines
+        // The separator row under the header.
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let cells: Vec<_> = line.split('|').collect();
+            if cells.len() != columns.count {
+                warnings.push(ParseWarning::BadColumnCount(line.to_string()));
+                return None;
+            }
+
+            let (original_name, homepage) = parse_markdown_link(cells[columns.name].trim());
+            if original_name.is_empty() || original_name == "reserved" {
+                warnings.push(ParseWarning::ReservedOrEmpty(original_name.to_string()));
+                return None;
+            }
+
+            let name = match original_name_to_short(original_name) {
+                Ok(n) => n,
+                Err(error) => {
+                    warnings.push(ParseWarning::NameError {
+                        name: original_name.to_string(),
+                        error,
+                    });
+                    return None;
+                }
+            };
+
+            let id = match cells[columns.id].trim().parse::<u32>() {
+                Ok(id) => id,
+                Err(_) => {
+                    warnings.push(ParseWarning::BadId {
+                        name: original_name.to_string(),
+                        id: cells[columns.id].trim().to_string(),
+                    });
+                    return None;
+                }
+            };
+
+            progress!("Processing coin: {} (ID: {})", original_name, id);
+
+            Some(CoinType {
+                id,
+                ids: vec![],
+                path_component: cells[columns.path_component].trim().to_string(),
+                symbol: Some(prepend_enum(
+                    parse_markdown_link(cells[columns.symbol].trim()).0,
+                ))
+                .map(|symbol| match symbol.as_str() {
+                    "$DAG" => "DAG".to_string(),
+                    symbol => symbol.to_string(),
+                })
+                .filter(|symbol| !symbol.is_empty()),
+                name: name.to_string(),
+                original_name: original_name.to_string(),
+                homepage: homepage.map(str::to_string),
+            })
+        })
+        .collect();
+    progress!("Parsed {} coins", coin_types.len());
+    Ok((coin_types, warnings))
+}
+
+/// Curated corrections to how `dedup` groups SLIP-0044 rows into coins, for rows whose `group_key` is wrong, e.g.
+/// when the `(...)` notes that `original_name_to_short` strips were all that told two coins apart.
+#[derive(Debug, Default)]
+pub(crate) struct Overrides {
+    /// Coin types merged into one coin whatever their group keys, with the name and symbol of the first of their
+    /// rows.
+    pub(crate) merge: &'static [&'static [u32]],
+    /// Coin types each kept a coin of their own, even if other rows have the same group key.
+    pub(crate) split: &'static [u32],
+}
+
+/// Overrides applied when generating `coin.rs`. Add entries here rather than new rules in `original_name_to_short`
+/// when only a few rows are grouped wrong.
+pub(crate) const OVERRIDES: Overrides = Overrides {
+    merge: &[],
+    split: &[],
+};
+
+/// Group of a row in `dedup`: its `group_key`, unless `Overrides` force it into a merge or a split.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum Group {
+    Key(String, Option<String>),
+    /// Index into `Overrides::merge`.
+    Merge(usize),
+    /// Coin type in `Overrides::split`.
+    Split(u32),
+}
+
+/// Key of the rows `dedup` merges into one coin: the name normalized by `original_name_to_short` and the symbol.
+fn group_key(coin_type: &CoinType) -> (String, Option<String>) {
+    (coin_type.name.clone(), coin_type.symbol.clone())
+}
+
+/// Merges SLIP-0044 rows describing the same coin, i.e. with the same `group_key` or forced together by
+/// `overrides`, into one coin with all their IDs, lowest first.
+///
+/// Rows with the same normalized name but different symbols stay separate coins, with the symbol (or the IDs if
+/// there's none, or another coin of that name has the same symbol) appended to the name to tell them apart. Rows with
+/// the same symbol but different names stay separate coins too, all but the first one get it as a duplicate symbol
+/// when writing `coin.rs`.
+pub(crate) fn dedup(
+    coin_types: impl IntoIterator<Item = CoinType>,
+    overrides: &Overrides,
+) -> Vec<CoinType> {
+    let mut unique = Vec::<CoinType>::new();
+    let mut positions = HashMap::<Group, usize>::new();
+    for coin_type in coin_types {
+        let group = match overrides
+            .merge
+            .iter()
+            .position(|ids| ids.contains(&coin_type.id))
+        {
+            Some(merge) => Group::Merge(merge),
+            None if overrides.split.contains(&coin_type.id) => Group::Split(coin_type.id),
+            None => {
+                let (name, symbol) = group_key(&coin_type);
+                Group::Key(name, symbol)
+            }
+        };
+        match positions.entry(group) {
+            Entry::Occupied(position) => unique[*position.get()].ids.push(coin_type.id),
+            Entry::Vacant(position) => {
+                position.insert(unique.len());
+                unique.push(CoinType {
+                    ids: vec![coin_type.id],
+                    ..coin_type
+                });
+            }
+        }
+    }
+
+    let mut name_counts = HashMap::<String, usize>::new();
+    let mut key_counts = HashMap::<(String, Option<String>), usize>::new();
+    for coin_type in &mut unique {
+        coin_type.ids.sort_unstable();
+        coin_type.ids.dedup();
+        coin_type.id = coin_type.ids[0];
+        *name_counts.entry(coin_type.name.clone()).or_default() += 1;
+        *key_counts.entry(group_key(coin_type)).or_default() += 1;
+    }
+
+    unique
+        .into_iter()
+        .map(|coin_type| {
+            if name_counts[&coin_type.name] == 1 {
+                return coin_type;
+            }
+            progress!("Found duplicate coins for name: {}", coin_type.name);
+            let suffix = match &coin_type.symbol {
+                Some(symbol) if key_counts[&group_key(&coin_type)] == 1 => symbol.clone(),
+                _ => coin_type.ids.iter().join("_"),
+            };
+            CoinType {
+                name: format!("{}_{}", coin_type.name, suffix),
+                ..coin_type
+            }
+        })
+        .collect()
+}
+
+/// Fails with a report of every coin type that is the primary ID of more than one of `coin_types`, since
+/// `Coin::from_id` can only round-trip one of them.
+///
+/// Testnets are exempt, they share `TESTNET_COIN_TYPE` and resolve to the first of them.
+pub(crate) fn assert_unique_primaries(coin_types: &[CoinType]) -> Result<(), String> {
+    let mut names_by_id = HashMap::<u32, Vec<&str>>::new();
+    for coin_type in coin_types {
+        names_by_id
+            .entry(coin_type.id)
+            .or_default()
+            .push(&coin_type.name);
+    }
+
+    let report: Vec<_> = names_by_id
+        .into_iter()
+        .filter(|(id, names)| *id != TESTNET_COIN_TYPE && names.len() > 1)
+        .sorted()
+        .map(|(id, names)| format!("coin type {} is the primary ID of {}", id, names.join(", ")))
+        .collect();
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report.join("\n"))
+    }
+}
+
+/// Fails with a report of every name of `coin_types` that isn't a valid, unique `Coin` variant name, e.g. one that
+/// `dedup` suffixed with a symbol that isn't an identifier fragment or that another coin already has, since `coin.rs`
+/// wouldn't compile.
+pub(crate) fn assert_valid_names(coin_types: &[CoinType]) -> Result<(), String> {
+    let mut ids_by_name = HashMap::<&str, Vec<u32>>::new();
+    for coin_type in coin_types {
+        ids_by_name
+            .entry(&coin_type.name)
+            .or_default()
+            .push(coin_type.id);
+    }
+
+    let report: Vec<_> = ids_by_name
+        .into_iter()
+        .sorted()
+        .flat_map(|(name, ids)| {
+            let invalid =
+                (!is_identifier(name)).then(|| format!("{} is not a valid variant name", name));
+            let duplicate = (ids.len() > 1).then(|| {
+                format!(
+                    "{} is the variant name of coin types {}",
+                    name,
+                    ids.iter().join(", ")
+                )
+            });
+            invalid.into_iter().chain(duplicate)
+        })
+        .collect();
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report.join("\n"))
+    }
+}
+
+/// Fails with a report of every row of `coin_types`, as returned by `parse`, whose path component isn't its coin
+/// type with the hardened bit set, since `Coin::hardened_path_component` is derived from the coin type.
+///
+/// Run it before `dedup`, which keeps the path component of the first row of a coin only.
+pub(crate) fn assert_path_components(coin_types: &[CoinType]) -> Result<(), String> {
+    let report: Vec<_> = coin_types
+        .iter()
+        .filter_map(|coin_type| {
+            let expected = coin_type.id | HARDENED;
+            let path_component = coin_type
+                .path_component
+                .strip_prefix("0x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+            (path_component != Some(expected)).then(|| {
+                format!(
+                    "{}: path component `{}` isn't coin type {} hardened, {:#010x}",
+                    coin_type.original_name, coin_type.path_component, coin_type.id, expected
+                )
+            })
+        })
+        .collect();
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report.join("\n"))
+    }
+}
+
+/// Fails with every coin `parse` dropped and why, i.e. the `warnings` other than reserved rows and lines outside the
+/// table, so that `--strict` catches SLIP-0044 names that need a new rule in `original_name_to_short`.
+pub(crate) fn assert_nothing_dropped(warnings: &[ParseWarning]) -> Result<(), String> {
+    let dropped: Vec<_> = warnings.iter().filter(|w| w.drops_coin()).collect();
+    if dropped.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "dropped {} coins:\n{}",
+            dropped.len(),
+            dropped.iter().join("\n")
+        ))
+    }
+}
+
+/// `coins!` macro entry of a coin, with its rustdoc, exactly as written to `coin.rs`.
+///
+/// A `duplicate_symbol` is written as a string instead of a `Symbol` variant, since another coin with a lower coin
+/// type already has it. Hand-added coins can use this to get their entry right.
+fn format_macro_entry(
+    ids: &[u32],
+    name: &str,
+    original_name: &str,
+    symbol: Option<&str>,
+    duplicate_symbol: bool,
+    homepage: Option<&str>,
+) -> String {
+    let mut rustdoc_lines = vec![format!("/// Coin type: {}", ids.iter().join(", "))];
+    if let Some(symbol) = symbol {
+        rustdoc_lines.push(format!("/// Symbol: {}", symbol));
+    }
+    rustdoc_lines.push(format!("/// Coin: {}", original_name));
+
+    let symbol = symbol.map(escape_rust_string);
+    let (symbol, duplicate_symbol) = match symbol {
+        Some(symbol) if duplicate_symbol => (String::new(), format!("\"{}\"", symbol)),
+        Some(symbol) => (symbol, String::new()),
+        None => (String::new(), String::new()),
+    };
+
+    let homepage = homepage.map_or(String::new(), |homepage| format!(" {:?},", homepage));
+
+    format!(
+        "    (\n        {}\n        [{}], {}, \"{}\", {}, {},{}\n    ),\n",
+        rustdoc_lines.join("\n        "),
+        ids.iter().join(","),
+        name,
+        escape_rust_string(original_name),
+        symbol,
+        duplicate_symbol,
+        homepage,
+    )
+}
+
+/// Text and URL of a markdown link `[text](url)`, or `input` as is and no URL if it isn't one.
+fn parse_markdown_link(input: &str) -> (&str, Option<&str>) {
+    let link = input
+        .strip_prefix('[')
+        .and_then(|link| link.strip_suffix(')'))
+        .and_then(|link| link.rsplit_once("]("));
+    match link {
+        Some((text, url)) => (text.trim(), Some(url.trim())),
+        None => (input, None),
+    }
+}
+
+fn original_name_to_short(original_name: &str) -> Result<String, String> {
+    let mut name = original_name.replace(' ', "");
+    name = name
+        .split_once('(')
+        .map_or(name.to_string(), |(name, _)| name.to_string());
+    name = prepend_enum(&name);
+
+    // Check direct mappings first
+    let name_match = match name.as_str() {
+        "Ether" => Ok("Ethereum"),
+        "EtherClassic" => Ok("EthereumClassic"),
+        name => Ok(name), // Default to original name if no mapping
+    };
+
+    // Then handle special characters if needed
+    if name.contains(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_') {
+        let special_match = match name.as_str() {
+            "Pl^g" => Ok("Plug"),
+            "BitcoinMatteo'sVision" => Ok("BitcoinMatteosVision"),
+            "Crypto.orgChain" => Ok("CryptoOrgChain"),
+            "Cocos-BCX" => Ok("CocosBCX"),
+            "Capricoin+" => Ok("CapricoinPlus"),
+            "Seele-N" => Ok("SeeleN"),
+            "IQ-Cash" => Ok("IQCash"),
+            "XinFin.Network" => Ok("XinFinNetwork"),
+            "Unit-e" => Ok("UnitE"),
+            "HARMONY-ONE" => Ok("HarmonyOne"),
+            "ThePower.io" => Ok("ThePower"),
+            "evan.network" => Ok("EvanNetwork"),
+            "Ether-1" => Ok("EtherOne"),
+            // Transliterated to `th`
+            "θ" => Ok("Theta"),
+            name => {
+                return name_match.and_then(|_| {
+                    transliterate(name)
+                        .ok_or_else(|| format!("unknown original coin name `{}`", name))
+                })
+            }
+        };
+        special_match.map(|name| name.to_string())
+    } else {
+        name_match.map(|name| name.to_string())
+    }
+}
+
+/// Identifier for a non-ASCII `name`, transliterated to ASCII, e.g. `aeternity` for `æternity`, or `None` if it's
+/// ASCII or still contains characters other than `[A-Za-z0-9_]` once transliterated.
+fn transliterate(name: &str) -> Option<String> {
+    if name.is_ascii() {
+        return None;
+    }
+    let name: String = deunicode::deunicode(name)
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .collect();
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    valid.then(|| prepend_enum(&name))
+}
+
+/// Whether `name` can be a `Coin` variant name, i.e. is `[A-Za-z_][A-Za-z0-9_]*` and not just `_`.
+fn is_identifier(name: &str) -> bool {
+    name != "_"
+        && name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+fn prepend_enum(name: &str) -> String {
+    if name.starts_with(char::is_numeric) {
+        ["_", name].join("")
+    } else {
+        name.to_string()
+    }
+}
+
+fn escape_rust_string(s: &str) -> String {
+    s.replace(['@', '^', '\'', '"', '\\', '$'], "")
+        .chars()
+        .filter(|c| {
+            c.is_ascii_alphanumeric()
+                || *c == '_'
+                || *c == ' '
+                || *c == '-'
+                || *c == '+'
+                || *c == '.'
+                || *c == '('
+                || *c == ')'
+        }) // Only allow these characters
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: u32, symbol: Option<&str>, name: &str, original_name: &str) -> CoinType {
+        CoinType {
+            id,
+            ids: vec![],
+            path_component: format!("0x{:08x}", id | 0x8000_0000),
+            symbol: symbol.map(str::to_string),
+            name: name.to_string(),
+            original_name: original_name.to_string(),
+            homepage: None,
+        }
+    }
+
+    /// `dedup` without overrides.
+    fn dedup_rows(coin_types: Vec<CoinType>) -> Vec<CoinType> {
+        dedup(coin_types, &Overrides::default())
+    }
+
+    fn names_and_ids(coin_types: Vec<CoinType>) -> Vec<(String, Vec<u32>)> {
+        coin_types
+            .into_iter()
+            .map(|coin_type| (coin_type.name, coin_type.ids))
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn dedup_merges_rows_of_one_coin() {
+        let coin_types = dedup_rows(vec![
+            row(498, Some("CR"), "Credits", "Credits"),
+            row(334, Some("CR"), "Credits", "Credits"),
+            row(30, Some("BAR"), "BarCoin", "Bar Coin"),
+            row(31, Some("BAR"), "BarCoin", "Bar coin"),
+        ]);
+
+        assert_eq!(
+            names_and_ids(coin_types),
+            vec![
+                ("BarCoin".to_string(), vec![30, 31]),
+                ("Credits".to_string(), vec![334, 498]),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_same_name_different_symbol() {
+        let coin_types = dedup_rows(vec![
+            row(10, Some("FOO"), "Foo", "Foo"),
+            row(11, Some("FOX"), "Foo", "Foo"),
+            row(12, None, "Foo", "Foo"),
+            row(13, None, "Foo", "Foo"),
+        ]);
+
+        assert_eq!(
+            names_and_ids(coin_types),
+            vec![
+                ("Foo_12_13".to_string(), vec![12, 13]),
+                ("Foo_FOO".to_string(), vec![10]),
+                ("Foo_FOX".to_string(), vec![11]),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_same_symbol_different_name() {
+        let coin_types = dedup_rows(vec![
+            row(20, Some("ABC"), "Alpha", "Alpha"),
+            row(21, Some("ABC"), "Beta", "Beta"),
+        ]);
+
+        assert_eq!(
+            names_and_ids(coin_types),
+            vec![
+                ("Alpha".to_string(), vec![20]),
+                ("Beta".to_string(), vec![21]),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_keeps_testnets_sharing_coin_type() {
+        let coin_types = dedup_rows(vec![
+            row(1, None, "Testnet", "Testnet (all coins)"),
+            row(1, Some("SBTC"), "SignetTestnet", "Signet Testnet"),
+            row(1, None, "Testnet", "Testnet (all coins)"),
+        ]);
+
+        assert!(coin_types
+            .iter()
+            .all(|coin_type| coin_type.ids == [TESTNET_COIN_TYPE]));
+        assert_eq!(
+            names_and_ids(coin_types),
+            vec![
+                ("SignetTestnet".to_string(), vec![1]),
+                ("Testnet".to_string(), vec![1]),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_overrides() {
+        let rows = || {
+            vec![
+                row(498, Some("CR"), "Credits", "Credits"),
+                row(334, Some("CR"), "Credits", "Credits"),
+                row(30, Some("BAR"), "BarCoin", "Bar Coin"),
+                row(32, Some("BAR2"), "BarCoinTwo", "Bar Coin (v2)"),
+            ]
+        };
+
+        let merged = dedup(
+            rows(),
+            &Overrides {
+                merge: &[&[32, 30]],
+                split: &[],
+            },
+        );
+        assert_eq!(
+            names_and_ids(merged),
+            vec![
+                ("BarCoin".to_string(), vec![30, 32]),
+                ("Credits".to_string(), vec![334, 498]),
+            ]
+        );
+
+        let split = dedup(
+            rows(),
+            &Overrides {
+                merge: &[],
+                split: &[498],
+            },
+        );
+        assert_eq!(assert_valid_names(&split), Ok(()));
+        assert_eq!(
+            names_and_ids(split),
+            vec![
+                ("BarCoin".to_string(), vec![30]),
+                ("BarCoinTwo".to_string(), vec![32]),
+                ("Credits_334".to_string(), vec![334]),
+                ("Credits_498".to_string(), vec![498]),
+            ]
+        );
+    }
+
+    #[test]
+    fn unique_primaries() {
+        let coin_types = dedup_rows(vec![
+            row(50, Some("UNO"), "Uno", "Uno"),
+            row(40, Some("ONE"), "One", "One"),
+            row(41, Some("TWO"), "Two", "Two"),
+            row(40, Some("UNO"), "Uno", "Uno"),
+            row(39, Some("TWO"), "Two", "Two"),
+            row(40, None, "Eins", "Eins"),
+            row(50, None, "Fifty", "Fifty"),
+        ]);
+
+        assert_eq!(
+            assert_unique_primaries(&coin_types),
+            Err("coin type 40 is the primary ID of Uno, One, Eins".to_string())
+        );
+        assert_eq!(
+            assert_unique_primaries(&dedup_rows(vec![
+                row(1, None, "Testnet", "Testnet (all coins)"),
+                row(1, Some("SBTC"), "SignetTestnet", "Signet Testnet"),
+                row(39, Some("TWO"), "Two", "Two"),
+                row(41, Some("TWO"), "Two", "Two"),
+            ])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn valid_names() {
+        // `dedup` suffixes the first two with their symbols, giving the third one's name.
+        let coin_types = dedup_rows(vec![
+            row(10, Some("B"), "Foo", "Foo"),
+            row(11, Some("C"), "Foo", "Foo"),
+            row(12, None, "Foo_B", "Foo B"),
+            row(20, Some("X-Y"), "Bar", "Bar"),
+            row(21, Some("Z"), "Bar", "Bar"),
+            row(30, None, "Baz", "Baz"),
+        ]);
+
+        assert_eq!(
+            assert_valid_names(&coin_types),
+            Err("Bar_X-Y is not a valid variant name\n\
+                 Foo_B is the variant name of coin types 10, 12"
+                .to_string())
+        );
+        assert_eq!(assert_valid_names(&coin_types[4..]), Ok(()));
+        assert!(is_identifier("_8BIT"));
+        assert!(!is_identifier("_"));
+        assert!(!is_identifier("8BIT"));
+    }
+
+    #[test]
+    fn path_components() {
+        let mut bitcoin = row(0, Some("BTC"), "Bitcoin", "Bitcoin");
+        assert_eq!(bitcoin.path_component, "0x80000000");
+        assert_eq!(assert_path_components(&[bitcoin]), Ok(()));
+
+        bitcoin = row(0, Some("BTC"), "Bitcoin", "Bitcoin");
+        bitcoin.path_component = "0x80000001".to_string();
+        let mut typo = row(7, None, "Typo", "Typo");
+        typo.path_component = "0x8000000g".to_string();
+        assert_eq!(
+            assert_path_components(&[bitcoin, row(1, None, "Testnet", "Testnet"), typo]),
+            Err(
+                "Bitcoin: path component `0x80000001` isn't coin type 0 hardened, 0x80000000\n\
+                 Typo: path component `0x8000000g` isn't coin type 7 hardened, 0x80000007"
+                    .to_string()
+            )
+        );
+
+        let markdown = [
+            SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | BTC     | Bitcoin                           |",
+            "| 60         | 0x8000003c                    | ETH     | Ether                             |",
+        ]
+        .join("\n");
+        assert_eq!(assert_path_components(&parse(&markdown).unwrap().0), Ok(()));
+    }
+
+    #[test]
+    fn parse_rows() {
+        let markdown = [
+            "# SLIP-0044 : Registered coin types for BIP-0044",
+            SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | BTC     | Bitcoin                           |",
+            "| 1          | 0x80000001                    |         | Testnet (all coins)               |",
+            "| 11         | 0x8000000b                    |         | reserved                          |",
+            "| 60         | 0x8000003c                    | ETH     | Ether                             |",
+            "not a row",
+        ]
+        .join("\n");
+
+        let (coin_types, warnings) = parse(&markdown).unwrap();
+        assert_eq!(
+            warnings,
+            [
+                ParseWarning::ReservedOrEmpty("reserved".to_string()),
+                ParseWarning::BadColumnCount("not a row".to_string()),
+            ]
+        );
+        let coin_types: Vec<_> = coin_types
+            .into_iter()
+            .map(|coin_type| (coin_type.id, coin_type.symbol, coin_type.name))
+            .collect();
+        assert_eq!(
+            coin_types,
+            vec![
+                (0, Some("BTC".to_string()), "Bitcoin".to_string()),
+                (1, None, "Testnet".to_string()),
+                (60, Some("ETH".to_string()), "Ethereum".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_reordered_columns() {
+        let markdown = [
+            "| Symbol | Coin    | Coin type | Added   | Path component (`coin_type'`) |",
+            "| ------ | ------- | --------- | ------- | ----------------------------- |",
+            "| BTC    | Bitcoin | 0         | 2014    | 0x80000000                    |",
+            "| ETH    | Ether   | 60        | 2015    | 0x8000003c                    |",
+        ]
+        .join("\n");
+
+        let (coin_types, warnings) = parse(&markdown).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        let coin_types: Vec<_> = coin_types
+            .into_iter()
+            .map(|coin_type| {
+                (
+                    coin_type.id,
+                    coin_type.path_component,
+                    coin_type.symbol,
+                    coin_type.name,
+                )
+            })
+            .collect();
+        assert_eq!(
+            coin_types,
+            vec![
+                (
+                    0,
+                    "0x80000000".to_string(),
+                    Some("BTC".to_string()),
+                    "Bitcoin".to_string()
+                ),
+                (
+                    60,
+                    "0x8000003c".to_string(),
+                    Some("ETH".to_string()),
+                    "Ethereum".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_missing_column() {
+        let header =
+            "| Coin type  | Path component (`coin_type'`) | Coin                              |";
+        let markdown = [
+            header,
+            "| ---------- | ----------------------------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | Bitcoin                           |",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            parse(&markdown).unwrap_err(),
+            format!("SLIP-0044 table has no `Symbol` column: {}", header)
+        );
+        assert_eq!(
+            parse("# SLIP-0044\n\nNo table.").unwrap_err(),
+            "SLIP-0044 markdown has no table with a `Coin type` column"
+        );
+    }
+
+    #[test]
+    fn unicode_names() {
+        for (original_name, name) in [
+            ("æternity", "aeternity"),
+            ("θ", "Theta"),
+            ("Биткоин", "Bitkoin"),
+            ("Эфир Классик", "EfirKlassik"),
+            ("Café Monnaie", "CafeMonnaie"),
+            ("Ñandú (Testnet)", "Nandu"),
+            ("Žluťoučký kůň", "Zlutouckykun"),
+            ("①Coin", "_1Coin"),
+        ] {
+            let short = original_name_to_short(original_name).unwrap();
+            assert_eq!(short, name);
+            assert!(is_identifier(&short), "{}", short);
+        }
+        assert!(original_name_to_short("Café-Coin").is_err());
+        assert!(original_name_to_short("Snow*Flake").is_err());
+    }
+
+    #[test]
+    fn markdown_links() {
+        assert_eq!(
+            parse_markdown_link("[Bitcoin](https://bitcoin.org)"),
+            ("Bitcoin", Some("https://bitcoin.org"))
+        );
+        assert_eq!(
+            parse_markdown_link("[Testnet (all coins)](https://example.com/a_(b))"),
+            ("Testnet (all coins)", Some("https://example.com/a_(b)"))
+        );
+        assert_eq!(parse_markdown_link("Bitcoin"), ("Bitcoin", None));
+        assert_eq!(parse_markdown_link("[Bitcoin]"), ("[Bitcoin]", None));
+
+        let markdown = [
+            SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | [BTC](https://bitcoin.org) | [Bitcoin](https://bitcoin.org) |",
+            "| 60         | 0x8000003c                    | ETH     | Ether                             |",
+        ]
+        .join("\n");
+        let coin_types: Vec<_> = parse(&markdown)
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|coin_type| {
+                (
+                    coin_type.symbol,
+                    coin_type.name,
+                    coin_type.original_name,
+                    coin_type.homepage,
+                )
+            })
+            .collect();
+        assert_eq!(
+            coin_types,
+            vec![
+                (
+                    Some("BTC".to_string()),
+                    "Bitcoin".to_string(),
+                    "Bitcoin".to_string(),
+                    Some("https://bitcoin.org".to_string())
+                ),
+                (
+                    Some("ETH".to_string()),
+                    "Ethereum".to_string(),
+                    "Ether".to_string(),
+                    None
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn symbol_owners_are_lowest_coin_types() {
+        let coin_types = [
+            row(337, Some("CPC"), "CPChain", "CPChain"),
+            row(9, None, "Unnamed", "Unnamed"),
+            row(289, Some("CPC"), "Capricoin", "Capricoin"),
+            row(0, Some("BTC"), "Bitcoin", "Bitcoin"),
+        ];
+        fn owners(coin_types: Vec<&CoinType>) -> Vec<(&str, u32)> {
+            symbol_owners(&coin_types).into_iter().sorted().collect()
+        }
+
+        let expected = vec![("BTC", 0), ("CPC", 289)];
+        assert_eq!(owners(coin_types.iter().collect()), expected);
+        assert_eq!(owners(coin_types.iter().rev().collect()), expected);
+    }
+
+    #[test]
+    fn strict() {
+        let markdown = [
+            SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | BTC     | Bitcoin                           |",
+            "| 70         | 0x80000046                    | SNO     | Snow*Flake                        |",
+            "| 7x         | 0x80000047                    | TYP     | Typo                              |",
+        ]
+        .join("\n");
+
+        let (coin_types, warnings) = parse(&markdown).unwrap();
+        assert_eq!(coin_types.len(), 1);
+        assert_eq!(
+            assert_nothing_dropped(&warnings),
+            Err("dropped 2 coins:\n\
+                 Snow*Flake: unknown original coin name `Snow*Flake`\n\
+                 Typo: invalid ID `7x`"
+                .to_string())
+        );
+        assert_eq!(assert_nothing_dropped(&[]), Ok(()));
+        assert_eq!(
+            assert_nothing_dropped(&[ParseWarning::ReservedOrEmpty("reserved".to_string())]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn parse_warnings() {
+        let markdown = [
+            SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | BTC     | Bitcoin                           |",
+            "| 11         | 0x8000000b                    |         | reserved                          |",
+            "| 12         | 0x8000000c                    |         |                                   |",
+            "| 70         | 0x80000046                    | SNO     | Snow*Flake                        |",
+            "| 7x         | 0x80000047                    | TYP     | Typo                              |",
+            "| 72         | 0x80000048                    | Short row |",
+            "",
+        ]
+        .join("\n");
+
+        let (coin_types, warnings) = parse(&markdown).unwrap();
+        assert_eq!(coin_types.len(), 1);
+        assert_eq!(
+            warnings,
+            [
+                ParseWarning::ReservedOrEmpty("reserved".to_string()),
+                ParseWarning::ReservedOrEmpty(String::new()),
+                ParseWarning::NameError {
+                    name: "Snow*Flake".to_string(),
+                    error: "unknown original coin name `Snow*Flake`".to_string(),
+                },
+                ParseWarning::BadId {
+                    name: "Typo".to_string(),
+                    id: "7x".to_string(),
+                },
+                ParseWarning::BadColumnCount(
+                    "| 72         | 0x80000048                    | Short row |".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            summarize(&warnings),
+            "skipped 5 rows: 1 with a bad column count, 2 reserved or empty, 1 with a name error, 1 with a bad ID"
+        );
+        assert_eq!(warnings[3].to_string(), "Typo: invalid ID `7x`");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn parse_events() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing_subscriber::layer::{Context, SubscriberExt};
+        use tracing_subscriber::Layer;
+
+        /// Collects the messages of all events.
+        #[derive(Clone, Default)]
+        struct Messages(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Messages {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    self.0.lock().unwrap().push(format!("{:?}", value));
+                }
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for Messages {
+            fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+                event.record(&mut self.clone());
+            }
+        }
+
+        let messages = Messages::default();
+        let subscriber = tracing_subscriber::registry().with(messages.clone());
+        let markdown = [
+            SLIP_044_MARKDOWN_HEADER,
+            "| ---------- | ----------------------------- | ------- | --------------------------------- |",
+            "| 0          | 0x80000000                    | BTC     | Bitcoin                           |",
+            "| 11         | 0x8000000b                    |         | reserved                          |",
+            "| 60         | 0x8000003c                    | ETH     | Ether                             |",
+        ]
+        .join("\n");
+        tracing::subscriber::with_default(subscriber, || parse(&markdown).unwrap());
+
+        let messages = messages.0.lock().unwrap();
+        assert!(
+            messages.contains(&"Parsed 2 coins".to_string()),
+            "{:?}",
+            messages
+        );
+        // Skipped rows are returned for `summarize` instead of logged.
+        assert!(
+            !messages.iter().any(|message| message.contains("reserved")),
+            "{:?}",
+            messages
+        );
+    }
+
+    #[test]
+    fn macro_entry_matches_coin_rs() {
+        let coin_rs = include_str!("../../coin.rs");
+
+        let bitcoin = format_macro_entry(&[0], "Bitcoin", "Bitcoin", Some("BTC"), false, None);
+        assert!(coin_rs.contains(&bitcoin), "{}", bitcoin);
+
+        let credits =
+            format_macro_entry(&[334, 498], "Credits", "Credits", Some("CS"), false, None);
+        assert!(coin_rs.contains(&credits), "{}", credits);
+
+        let cp_chain = format_macro_entry(&[337], "CPChain", "CPChain", Some("CPC"), true, None);
+        assert!(coin_rs.contains(&cp_chain), "{}", cp_chain);
+
+        let open_assets = format_macro_entry(&[21], "OpenAssets", "Open Assets", None, false, None);
+        assert_eq!(
+            open_assets,
+            "    (\n        /// Coin type: 21\n        /// Coin: Open Assets\n        [21], OpenAssets, \"Open Assets\", , ,\n    ),\n"
+        );
+        assert!(coin_rs.contains(&open_assets));
+        assert_eq!(
+            format_macro_entry(&[0], "Bitcoin", "Bitcoin", Some("BTC"), false, Some("https://bitcoin.org")),
+            "    (\n        /// Coin type: 0\n        /// Symbol: BTC\n        /// Coin: Bitcoin\n        \
+             [0], Bitcoin, \"Bitcoin\", BTC, , \"https://bitcoin.org\",\n    ),\n"
+        );
+    }
+
+    #[test]
+    fn render_registries() {
+        let mut coin_types: Vec<_> = COMMON_COIN_TYPES
+            .iter()
+            .map(|&id| row(id, None, &format!("Common{}", id), "Common"))
+            .collect();
+        coin_types.push(row(1, None, "Testnet", "Testnet (all coins)"));
+        coin_types.push(row(70, Some("SNO"), "Snow", "Snow"));
+        let coin_types = dedup_rows(coin_types);
+
+        let coin_rs = render(&coin_types, "v1", 1700000000).unwrap();
+        assert!(coin_rs.starts_with(
+            "// Code generated by src/bin/parse_coins.rs; DO NOT EDIT.\n\
+             // SLIP-0044 source: v1, generated at Unix time 1700000000.\n"
+        ));
+        assert!(coin_rs.contains("        [70], Snow, \"Snow\", SNO, ,\n"));
+        assert!(coin_rs.contains("            Coin::Common714,\n"));
+        let counts: Vec<_> = coin_rs
+            .lines()
+            .filter(|line| line.starts_with("pub const COIN_COUNT"))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                format!(
+                    "pub const COIN_COUNT: usize = {};",
+                    COMMON_COIN_TYPES.len() + 2
+                ),
+                format!(
+                    "pub const COIN_COUNT: usize = {};",
+                    COMMON_COIN_TYPES.len() + 1
+                ),
+                "pub const COIN_COUNT: usize = 2;".to_string(),
+            ]
+        );
+        let bitcoin_only = coin_rs
+            .split("#[cfg(not(any(feature = \"full\", feature = \"core-coins\")))]\ncoins!(")
+            .nth(1)
+            .unwrap();
+        assert!(bitcoin_only.contains("        vec![Coin::Common0]\n"));
+        assert!(coin_rs.ends_with("pub const SLIP44_SOURCE_REF: &str = \"v1\";\n"));
+
+        let err = render(&coin_types[1..], "v1", 1700000000).unwrap_err();
+        assert_eq!(err.to_string(), "common coin type 0 is not in SLIP-0044");
+    }
+}
@@ -1,5 +1,7 @@
 // Code generated by src/bin/parse_coins.rs; DO NOT EDIT.
+// SLIP-0044 source: master, generated at Unix time 1791951454.
 use crate::coins;
+#[cfg(feature = "full")]
 coins!(
     (
         /// Coin type: 0
@@ -7303,3 +7305,333 @@ coins!(
         [1179993461], HuaXiaChain, "HuaXia Chain", HXC, ,
     ),
 );
+#[cfg(feature = "full")]
+impl Coin {
+    /// Largest coin type in the registry.
+    pub const MAX_COIN_TYPE: u32 = 1179993461;
+    /// Number of decimal digits in [Coin::MAX_COIN_TYPE], for aligning coin type columns.
+    pub const MAX_ID_DIGITS: usize = 10;
+    /// Curated list of popular coins, most popular first.
+    #[cfg(feature = "alloc")]
+    pub fn common() -> Vec<Coin> {
+        vec![
+            Coin::Bitcoin,
+            Coin::Ethereum,
+            Coin::Binance,
+            Coin::XRP,
+            Coin::Solana,
+            Coin::Tron,
+            Coin::Dogecoin,
+            Coin::Cardano,
+            Coin::BitcoinCash,
+            Coin::HederaHBAR,
+            Coin::StellarLumens,
+            Coin::Avalanche,
+            Coin::Sui,
+            Coin::Litecoin,
+            Coin::Polkadot,
+            Coin::Monero,
+            Coin::TON,
+            Coin::NEARProtocol,
+            Coin::Aptos,
+            Coin::EthereumClassic,
+            Coin::Atom,
+            Coin::Matic,
+            Coin::Arbitrum,
+            Coin::Filecoin,
+            Coin::Algorand,
+            Coin::OptimisticEthereum,
+            Coin::Stacks,
+            Coin::Tezos,
+            Coin::Zcash,
+            Coin::Dash,
+            Coin::EOS,
+            Coin::Kusama,
+        ]
+    }
+}
+/// Number of coins in the registry, i.e. of [Coin] variants.
+#[cfg(feature = "full")]
+pub const COIN_COUNT: usize = 1221;
+#[cfg(all(not(feature = "full"), feature = "core-coins"))]
+coins!(
+    (
+        /// Coin type: 0
+        /// Symbol: BTC
+        /// Coin: Bitcoin
+        [0], Bitcoin, "Bitcoin", BTC, ,
+    ),
+    (
+        /// Coin type: 1
+        /// Coin: Testnet (all coins)
+        [1], Testnet, "Testnet (all coins)", , ,
+    ),
+    (
+        /// Coin type: 2
+        /// Symbol: LTC
+        /// Coin: Litecoin
+        [2], Litecoin, "Litecoin", LTC, ,
+    ),
+    (
+        /// Coin type: 3
+        /// Symbol: DOGE
+        /// Coin: Dogecoin
+        [3], Dogecoin, "Dogecoin", DOGE, ,
+    ),
+    (
+        /// Coin type: 5
+        /// Symbol: DASH
+        /// Coin: Dash
+        [5], Dash, "Dash", DASH, ,
+    ),
+    (
+        /// Coin type: 60
+        /// Symbol: ETH
+        /// Coin: Ether
+        [60], Ethereum, "Ether", ETH, ,
+    ),
+    (
+        /// Coin type: 61
+        /// Symbol: ETC
+        /// Coin: Ether Classic
+        [61], EthereumClassic, "Ether Classic", ETC, ,
+    ),
+    (
+        /// Coin type: 118
+        /// Symbol: ATOM
+        /// Coin: Atom
+        [118], Atom, "Atom", ATOM, ,
+    ),
+    (
+        /// Coin type: 128
+        /// Symbol: XMR
+        /// Coin: Monero
+        [128], Monero, "Monero", XMR, ,
+    ),
+    (
+        /// Coin type: 133
+        /// Symbol: ZEC
+        /// Coin: Zcash
+        [133], Zcash, "Zcash", ZEC, ,
+    ),
+    (
+        /// Coin type: 144
+        /// Symbol: XRP
+        /// Coin: XRP
+        [144], XRP, "XRP", XRP, ,
+    ),
+    (
+        /// Coin type: 145
+        /// Symbol: BCH
+        /// Coin: Bitcoin Cash
+        [145], BitcoinCash, "Bitcoin Cash", BCH, ,
+    ),
+    (
+        /// Coin type: 148
+        /// Symbol: XLM
+        /// Coin: Stellar Lumens
+        [148], StellarLumens, "Stellar Lumens", XLM, ,
+    ),
+    (
+        /// Coin type: 194
+        /// Symbol: EOS
+        /// Coin: EOS
+        [194], EOS, "EOS", EOS, ,
+    ),
+    (
+        /// Coin type: 195
+        /// Symbol: TRX
+        /// Coin: Tron
+        [195], Tron, "Tron", TRX, ,
+    ),
+    (
+        /// Coin type: 283
+        /// Symbol: ALGO
+        /// Coin: Algorand
+        [283], Algorand, "Algorand", ALGO, ,
+    ),
+    (
+        /// Coin type: 354
+        /// Symbol: DOT
+        /// Coin: Polkadot
+        [354], Polkadot, "Polkadot", DOT, ,
+    ),
+    (
+        /// Coin type: 397
+        /// Symbol: NEAR
+        /// Coin: NEAR Protocol
+        [397], NEARProtocol, "NEAR Protocol", NEAR, ,
+    ),
+    (
+        /// Coin type: 434
+        /// Symbol: KSM
+        /// Coin: Kusama
+        [434], Kusama, "Kusama", KSM, ,
+    ),
+    (
+        /// Coin type: 461
+        /// Symbol: FIL
+        /// Coin: Filecoin
+        [461], Filecoin, "Filecoin", FIL, ,
+    ),
+    (
+        /// Coin type: 501
+        /// Symbol: SOL
+        /// Coin: Solana
+        [501], Solana, "Solana", SOL, ,
+    ),
+    (
+        /// Coin type: 607
+        /// Symbol: TON
+        /// Coin: TON
+        [607], TON, "TON", TON, ,
+    ),
+    (
+        /// Coin type: 614
+        /// Symbol: OPT
+        /// Coin: Optimistic Ethereum
+        [614], OptimisticEthereum, "Optimistic Ethereum", OPT, ,
+    ),
+    (
+        /// Coin type: 637
+        /// Symbol: APTOS
+        /// Coin: Aptos
+        [637], Aptos, "Aptos", APTOS, ,
+    ),
+    (
+        /// Coin type: 714
+        /// Symbol: BNB
+        /// Coin: Binance
+        [714], Binance, "Binance", BNB, ,
+    ),
+    (
+        /// Coin type: 784
+        /// Symbol: SUI
+        /// Coin: Sui
+        [784], Sui, "Sui", SUI, ,
+    ),
+    (
+        /// Coin type: 966
+        /// Symbol: MATIC
+        /// Coin: Matic
+        [966], Matic, "Matic", MATIC, ,
+    ),
+    (
+        /// Coin type: 1729
+        /// Symbol: XTZ
+        /// Coin: Tezos
+        [1729], Tezos, "Tezos", XTZ, ,
+    ),
+    (
+        /// Coin type: 1815
+        /// Symbol: ADA
+        /// Coin: Cardano
+        [1815], Cardano, "Cardano", ADA, ,
+    ),
+    (
+        /// Coin type: 3030
+        /// Symbol: HBAR
+        /// Coin: Hedera HBAR
+        [3030], HederaHBAR, "Hedera HBAR", HBAR, ,
+    ),
+    (
+        /// Coin type: 5757
+        /// Symbol: STX
+        /// Coin: Stacks
+        [5757], Stacks, "Stacks", STX, ,
+    ),
+    (
+        /// Coin type: 9000
+        /// Symbol: AVAX
+        /// Coin: Avalanche
+        [9000], Avalanche, "Avalanche", AVAX, ,
+    ),
+    (
+        /// Coin type: 9001
+        /// Symbol: ARB1
+        /// Coin: Arbitrum
+        [9001], Arbitrum, "Arbitrum", ARB1, ,
+    ),
+);
+#[cfg(all(not(feature = "full"), feature = "core-coins"))]
+impl Coin {
+    /// Largest coin type in the registry.
+    pub const MAX_COIN_TYPE: u32 = 9001;
+    /// Number of decimal digits in [Coin::MAX_COIN_TYPE], for aligning coin type columns.
+    pub const MAX_ID_DIGITS: usize = 4;
+    /// Curated list of popular coins, most popular first.
+    #[cfg(feature = "alloc")]
+    pub fn common() -> Vec<Coin> {
+        vec![
+            Coin::Bitcoin,
+            Coin::Ethereum,
+            Coin::Binance,
+            Coin::XRP,
+            Coin::Solana,
+            Coin::Tron,
+            Coin::Dogecoin,
+            Coin::Cardano,
+            Coin::BitcoinCash,
+            Coin::HederaHBAR,
+            Coin::StellarLumens,
+            Coin::Avalanche,
+            Coin::Sui,
+            Coin::Litecoin,
+            Coin::Polkadot,
+            Coin::Monero,
+            Coin::TON,
+            Coin::NEARProtocol,
+            Coin::Aptos,
+            Coin::EthereumClassic,
+            Coin::Atom,
+            Coin::Matic,
+            Coin::Arbitrum,
+            Coin::Filecoin,
+            Coin::Algorand,
+            Coin::OptimisticEthereum,
+            Coin::Stacks,
+            Coin::Tezos,
+            Coin::Zcash,
+            Coin::Dash,
+            Coin::EOS,
+            Coin::Kusama,
+        ]
+    }
+}
+/// Number of coins in the registry, i.e. of [Coin] variants.
+#[cfg(all(not(feature = "full"), feature = "core-coins"))]
+pub const COIN_COUNT: usize = 33;
+#[cfg(not(any(feature = "full", feature = "core-coins")))]
+coins!(
+    (
+        /// Coin type: 0
+        /// Symbol: BTC
+        /// Coin: Bitcoin
+        [0], Bitcoin, "Bitcoin", BTC, ,
+    ),
+    (
+        /// Coin type: 1
+        /// Coin: Testnet (all coins)
+        [1], Testnet, "Testnet (all coins)", , ,
+    ),
+);
+#[cfg(not(any(feature = "full", feature = "core-coins")))]
+impl Coin {
+    /// Largest coin type in the registry.
+    pub const MAX_COIN_TYPE: u32 = 1;
+    /// Number of decimal digits in [Coin::MAX_COIN_TYPE], for aligning coin type columns.
+    pub const MAX_ID_DIGITS: usize = 1;
+    /// Curated list of popular coins, most popular first.
+    #[cfg(feature = "alloc")]
+    pub fn common() -> Vec<Coin> {
+        vec![Coin::Bitcoin]
+    }
+}
+/// Number of coins in the registry, i.e. of [Coin] variants.
+#[cfg(not(any(feature = "full", feature = "core-coins")))]
+pub const COIN_COUNT: usize = 2;
+/// Version of the registry, `<epoch>+<SLIP-0044 revision>`. See [crate::is_compatible_with].
+pub const REGISTRY_VERSION: &str = "1+master";
+/// SLIP-0044 revision the registry was generated from, e.g. a commit SHA, `master` for the latest one at the time
+/// or `stdin`/`local` for markdown not fetched by `parse-coins`.
+pub const SLIP44_SOURCE_REF: &str = "master";
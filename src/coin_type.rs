@@ -0,0 +1,127 @@
+use core::fmt;
+
+use crate::Coin;
+
+/// Coin type that may be missing from this registry, e.g. from on-chain data newer than the crate, see
+/// [CoinType::classify].
+/// ```
+/// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+/// use nil_slip44::{Coin, CoinType};
+///
+/// assert_eq!(CoinType::classify(60), CoinType::Known(Coin::Ethereum));
+/// assert_eq!(CoinType::classify(2147483647), CoinType::Unknown(2147483647));
+/// assert_eq!(CoinType::classify(2147483647).id(), 2147483647);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
+pub enum CoinType {
+    /// Coin type of a coin, primary or secondary.
+    Known(Coin),
+    /// Coin type no coin has.
+    Unknown(u32),
+}
+
+impl CoinType {
+    /// Coin with coin type `id` if there's one (see [Coin::from_id]), else `id` as is. Never fails.
+    pub fn classify(id: u32) -> Self {
+        match Coin::from_id(id) {
+            Some(coin) => Self::Known(coin),
+            None => Self::Unknown(id),
+        }
+    }
+
+    /// Coin type, the primary [Coin::id] of a known coin.
+    ///
+    /// An unknown coin type round-trips unchanged, but a secondary ID of a known coin comes back as its primary ID.
+    /// ```
+    /// # #[cfg(feature = "full")] {
+    /// use nil_slip44::CoinType;
+    ///
+    /// assert_eq!(CoinType::classify(498).id(), 334);
+    /// # }
+    /// ```
+    pub const fn id(self) -> u32 {
+        match self {
+            Self::Known(coin) => coin.id(),
+            Self::Unknown(id) => id,
+        }
+    }
+
+    /// The coin, or `None` if the coin type is unknown.
+    pub const fn known(self) -> Option<Coin> {
+        match self {
+            Self::Known(coin) => Some(coin),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for CoinType {
+    /// Original name of a known coin (see [Coin::coin_name]), or `unknown coin type <id>`.
+    /// ```
+    /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+    /// use nil_slip44::CoinType;
+    ///
+    /// assert_eq!(CoinType::classify(60).to_string(), "Ether");
+    /// assert_eq!(CoinType::classify(2147483647).to_string(), "unknown coin type 2147483647");
+    /// # }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Known(coin) => fmt::Display::fmt(coin, f),
+            Self::Unknown(id) => write!(f, "unknown coin type {}", id),
+        }
+    }
+}
+
+impl From<u32> for CoinType {
+    fn from(id: u32) -> Self {
+        Self::classify(id)
+    }
+}
+
+impl From<Coin> for CoinType {
+    fn from(coin: Coin) -> Self {
+        Self::Known(coin)
+    }
+}
+
+impl Coin {
+    /// Coin with coin type `id`, or `id` wrapped as [CoinType::Unknown], see [CoinType::classify].
+    /// ```
+    /// use nil_slip44::{Coin, CoinType};
+    ///
+    /// assert_eq!(Coin::from_id_or_unknown(0), CoinType::Known(Coin::Bitcoin));
+    /// assert_eq!(Coin::from_id_or_unknown(2147483647), CoinType::Unknown(2147483647));
+    /// ```
+    pub fn from_id_or_unknown(id: u32) -> CoinType {
+        CoinType::classify(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CoinType;
+    use crate::Coin;
+
+    #[test]
+    fn classify() {
+        assert_eq!(CoinType::classify(0), CoinType::Known(Coin::Bitcoin));
+        assert_eq!(CoinType::classify(0), CoinType::from(Coin::Bitcoin));
+        assert_eq!(CoinType::from(1).known(), Some(Coin::Testnet));
+        assert_eq!(CoinType::classify(1).id(), 1);
+
+        let unknown = CoinType::classify(u32::MAX);
+        assert_eq!(unknown, CoinType::Unknown(u32::MAX));
+        assert_eq!(unknown.id(), u32::MAX);
+        assert_eq!(unknown.known(), None);
+        assert_eq!(CoinType::classify(unknown.id()), unknown);
+    }
+
+    #[cfg(feature = "full")]
+    #[test]
+    fn classify_secondary_id() {
+        assert_eq!(CoinType::classify(498), CoinType::Known(Coin::Credits));
+        assert_eq!(CoinType::classify(498).id(), 334);
+    }
+}
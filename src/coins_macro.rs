@@ -8,6 +8,7 @@ macro_rules! coins {
         $name:expr,
         $($symbol:ident)?,
         $($duplicate_symbol:expr)?
+        $(, $homepage:expr)?
         $(,)?
     )$(,)?),+) => {
         macro_rules! slip44_error {
@@ -19,9 +20,46 @@ macro_rules! coins {
             };
         }
 
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        #[allow(unused_imports)]
+        use $crate::prelude::*;
+
         #[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
-        #[allow(non_camel_case_types)]
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
         /// Coins according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
+        ///
+        /// A coin converts to three kinds of strings:
+        /// - [Display](core::fmt::Display) writes the unedited SLIP-0044 name, [Coin::coin_name], e.g. `Ether`;
+        /// - [`AsRef<str>`](AsRef) gives the variant name, [Coin::name], e.g. `Ethereum`, ASCII and a valid identifier,
+        ///   for config keys and the like;
+        /// - `From<Coin> for &str` gives the symbol, falling back to the variant name,
+        ///   [Coin::symbol_or_variant], e.g. `ETH`.
+        ///
+        /// SLIP-0044 keeps registering coins, so new variants are added in minor releases and `Coin` is
+        /// `#[non_exhaustive]`: a `match` on it outside this crate needs a wildcard arm. Removing or renaming a variant
+        /// is a breaking change, renamed variants stay reachable by [Coin::from_legacy_name].
+        /// ```compile_fail
+        /// use nil_slip44::Coin;
+        ///
+        /// fn is_bitcoin(coin: Coin) -> bool {
+        ///     match coin {
+        ///         Coin::Bitcoin => true,
+        ///         Coin::Testnet => false,
+        ///     }
+        /// }
+        /// ```
+        /// ```
+        /// use nil_slip44::Coin;
+        ///
+        /// fn is_bitcoin(coin: Coin) -> bool {
+        ///     match coin {
+        ///         Coin::Bitcoin => true,
+        ///         _ => false,
+        ///     }
+        /// }
+        /// assert!(is_bitcoin(Coin::Bitcoin));
+        /// ```
+        #[non_exhaustive]
         pub enum Coin {
             $(
                 $(#[$meta])*
@@ -29,80 +67,1827 @@ macro_rules! coins {
             )*
         }
 
-        impl std::fmt::Display for Coin {
+        impl core::fmt::Display for Coin {
+            /// Writes the unedited SLIP-0044 name, see [Coin::coin_name]. `Debug` writes the variant name instead.
             /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
             /// use nil_slip44::Coin;
             ///
             /// assert_eq!(Coin::Bitcoin.to_string(), "Bitcoin");
+            /// assert_eq!(Coin::Ethereum.to_string(), "Ether");
+            /// assert_eq!(format!("{:?}", Coin::Ethereum), "Ethereum");
+            /// # }
             /// ```
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{}", match self { $(Self::$ident => stringify!($ident), )* })
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.coin_name())
             }
         }
 
         impl Coin {
+            /// Every coin, ordered by coin type as in [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md)
+            /// spec. The order only depends on coin types, so it's stable across regenerations.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::ALL[0], Coin::Bitcoin);
+            /// assert_eq!(Coin::ALL.len(), Coin::all().len());
+            /// ```
+            pub const ALL: &'static [Coin] = &[$(Self::$ident, )*];
+
+            /// Every coin ID, secondary IDs included, with its coin, sorted by ID and then by the coin's coin type.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::ID_INDEX.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            /// assert!(Coin::ID_INDEX.contains(&(498, Coin::Credits)));
+            /// assert_eq!(Coin::ID_INDEX.len(), Coin::all().map(|coin| coin.ids().len()).sum::<usize>());
+            /// # }
+            /// ```
+            pub const ID_INDEX: &'static [(u32, Coin)] = &{
+                let mut index = [$(($id, Self::$ident), $($(($ids, Self::$ident), )+)?)*];
+                // Primary IDs come sorted already, so insertion sort only moves the few secondary IDs. Being stable,
+                // it also keeps coins sharing an ID in coin type order.
+                let mut i = 1;
+                while i < index.len() {
+                    let mut j = i;
+                    while j > 0 && index[j - 1].0 > index[j].0 {
+                        let previous = index[j - 1];
+                        index[j - 1] = index[j];
+                        index[j] = previous;
+                        j -= 1;
+                    }
+                    i += 1;
+                }
+                index
+            };
+
+            /// All coins, ordered by coin type as in [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::all().next(), Some(Coin::Bitcoin));
+            /// assert!(Coin::all().zip(Coin::all().skip(1)).all(|(a, b)| a.id() < b.id()));
+            /// ```
+            pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+                Self::ALL.iter().copied()
+            }
+
+            /// All coins, ordered by coin type from the highest down.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::all_rev().next().map(Coin::id), Some(Coin::MAX_COIN_TYPE));
+            /// assert!(Coin::all_rev().zip(Coin::all_rev().skip(1)).all(|(a, b)| a.id() > b.id()));
+            /// ```
+            pub fn all_rev() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+                Self::all().rev()
+            }
+
+            /// Coin at `index` in [Coin::all], or the last coin if `index` is out of range. Never panics.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::nth_or_last(0), Coin::Bitcoin);
+            /// assert_eq!(Coin::nth_or_last(usize::MAX), Coin::all().last().unwrap());
+            /// ```
+            pub fn nth_or_last(index: usize) -> Self {
+                Self::ALL[index.min(Self::ALL.len() - 1)]
+            }
+
+            /// Position of the coin in [Coin::all], the same as [Coin::index].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.registry_index(), 0);
+            /// assert_eq!(Coin::nth_or_last(Coin::Ethereum.registry_index()), Coin::Ethereum);
+            /// # }
+            /// ```
+            pub const fn registry_index(self) -> usize {
+                // Variants are declared in the same order as `ALL`.
+                self as usize
+            }
+
+            /// All coins with their [Coin::registry_index].
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::enumerate().all(|(index, coin)| index == coin.registry_index()));
+            /// ```
+            pub fn enumerate() -> impl DoubleEndedIterator<Item = (usize, Self)> + ExactSizeIterator {
+                Self::all().enumerate()
+            }
+
+            /// All coins, ordered case-insensitively by [Coin::coin_name].
+            ///
+            /// Coins whose names only differ in case keep their coin type order.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// let coins = Coin::all_by_name();
+            /// assert_eq!(coins.len(), Coin::all().len());
+            /// assert!(coins.windows(2).all(|w| w[0].coin_name().to_lowercase() <= w[1].coin_name().to_lowercase()));
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn all_by_name() -> Vec<Self> {
+                let mut coins: Vec<_> = Self::all().collect();
+                coins.sort_by_cached_key(|coin| coin.coin_name().to_lowercase());
+                coins
+            }
+
+            /// Coins whose unedited [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) name,
+            /// see [Coin::coin_name], is exactly `name`, in coin type order.
+            ///
+            /// A few names are shared by distinct coins, told apart by their symbols.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::all_from_original_name("Ether"), vec![Coin::Ethereum]);
+            /// assert_eq!(Coin::all_from_original_name("Helium"), vec![Coin::Helium_HLM, Coin::Helium_HNT]);
+            /// assert!(Coin::all_from_original_name("ether").is_empty());
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn all_from_original_name(name: &str) -> Vec<Self> {
+                Self::all().filter(|coin| coin.coin_name() == name).collect()
+            }
+
+            /// Coin whose unedited name is exactly `name`, or `None` if there's no such coin or more than one, see
+            /// [Coin::all_from_original_name].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_original_name("Ether"), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_original_name("Helium"), None);
+            /// assert_eq!(Coin::from_original_name("Ethereum"), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn from_original_name(name: &str) -> Option<Self> {
+                match Self::all_from_original_name(name).as_slice() {
+                    [coin] => Some(*coin),
+                    _ => None,
+                }
+            }
+
+            /// First coin, in coin type order, whose unedited name (see [Coin::coin_name]) is exactly `name`, spaces and
+            /// punctuation included.
+            ///
+            /// Complements [FromStr](core::str::FromStr), which parses symbols and variant names. Unlike
+            /// [Coin::from_original_name] a name shared by several coins resolves to the one with the lowest coin type,
+            /// and it doesn't allocate.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_coin_name("Bitcoin Cash"), Some(Coin::BitcoinCash));
+            /// assert_eq!(Coin::from_coin_name("Ether Classic"), Some(Coin::EthereumClassic));
+            /// assert_eq!(Coin::from_coin_name("Helium"), Some(Coin::Helium_HLM));
+            /// assert_eq!(Coin::from_coin_name("BitcoinCash"), None);
+            /// # }
+            /// ```
+            pub fn from_coin_name(name: &str) -> Option<Self> {
+                Self::all().find(|coin| coin.coin_name() == name)
+            }
+
+            /// Coin formerly known by the variant name `name`, for strings stored by older versions of this crate.
+            ///
+            /// Current variant names are not accepted, see [Coin::from_variant_str] for those.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_legacy_name("Ethereum"), None);
+            /// assert_eq!(Coin::from_legacy_name("Bitcoin"), None);
+            /// ```
+            pub fn from_legacy_name(name: &str) -> Option<Self> {
+                $crate::overlay::LEGACY_NAMES
+                    .iter()
+                    .find(|(legacy_name, _)| *legacy_name == name)
+                    .and_then(|(_, id)| Self::try_from(*id).ok())
+            }
+
             /// Coin ID according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
+            ///
+            /// This is the primary ID, the first of [Coin::ids]. `parse-coins` lists the IDs of a coin lowest first,
+            /// so for generated coins it's also the [Coin::canonical_id]. All lookups and conversions by coin type,
+            /// e.g. [Coin::from_id], [Coin::discriminant] and the [Ord] impl, use it.
             /// ```
+            /// # #[cfg(feature = "full")] {
             /// use nil_slip44::Coin;
             ///
             /// assert_eq!(Coin::Bitcoin.id(), 0);
+            /// assert_eq!(Coin::Credits.id(), 334);
+            /// # }
             /// ```
             pub const fn id(self) -> u32 { match self { $(Self::$ident => $id, )* } }
 
+            /// Stable numeric value of the coin, its primary [Coin::id], with [Coin::from_id] as the inverse.
+            ///
+            /// `Coin` is *not* `#[repr(u32)]` over coin types, since these have gaps and some coins have several, so
+            /// `coin as u32` is an unrelated declaration index. Use this for an allocation-free numeric round-trip
+            /// instead, e.g. to store a coin in a `u32` field.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.discriminant(), 60);
+            /// assert_eq!(Coin::from_id(Coin::Credits.discriminant()), Some(Coin::Credits));
+            /// # }
+            /// ```
+            pub const fn discriminant(self) -> u32 { self.id() }
+
+            /// Position of the coin in [Coin::ALL], with [Coin::from_index] as the inverse. Alias of
+            /// [Coin::registry_index].
+            ///
+            /// Unlike coin types, indices are dense, running from 0 to [Coin::ALL]`.len() - 1`, so they can index
+            /// arrays of per-coin data kept alongside the registry. They aren't stable though: a regeneration that adds
+            /// a coin shifts every coin after it, and the `core-coins` and `bitcoin-only` registries number their
+            /// coins differently. Persist [Coin::id] instead.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.index(), 0);
+            /// assert_eq!(Coin::ALL[Coin::Ethereum.index()], Coin::Ethereum);
+            /// # }
+            /// ```
+            pub const fn index(self) -> usize { self.registry_index() }
+
+            /// Coin at the given [Coin::index], or `None` past the last coin.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_index(0), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_index(Coin::ALL.len()), None);
+            /// ```
+            pub const fn from_index(index: usize) -> Option<Self> {
+                if index < Self::ALL.len() { Some(Self::ALL[index]) } else { None }
+            }
+
+            /// Coin ID as a [NonZeroU32](std::num::NonZeroU32), which is `None` only for [Coin::Bitcoin] (coin type 0).
+            ///
+            /// Useful with APIs that treat 0 as unset, and takes no more space than a bare `u32` when wrapped in an [Option].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use std::num::NonZeroU32;
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.coin_type_nonzero(), None);
+            /// assert_eq!(Coin::Ethereum.coin_type_nonzero(), NonZeroU32::new(60));
+            /// # }
+            /// ```
+            pub const fn coin_type_nonzero(self) -> Option<core::num::NonZeroU32> { core::num::NonZeroU32::new(self.id()) }
+
+            /// Coin type, or `None` for the testnet coin, so that deriving testnet keys takes an explicit
+            /// [Coin::id] call.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.mainnet_coin_type(), Some(0));
+            /// assert_eq!(Coin::Testnet.mainnet_coin_type(), None);
+            /// ```
+            pub const fn mainnet_coin_type(self) -> Option<u32> {
+                if self.is_testnet() {
+                    None
+                } else {
+                    Some(self.id())
+                }
+            }
+
+            /// Signed difference between the primary coin type and `base`, e.g. for encoding coins as offsets from a
+            /// base coin type. Every difference of two coin types fits, so this never overflows.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.coin_type_offset_from(Coin::Bitcoin.id()), 60);
+            /// assert_eq!(Coin::Bitcoin.coin_type_offset_from(Coin::Ethereum.id()), -60);
+            /// # }
+            /// ```
+            pub const fn coin_type_offset_from(self, base: u32) -> i64 {
+                self.id() as i64 - base as i64
+            }
+
             /// Coin IDs according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
             ///
-            /// Coins may have multiple IDs if both name and symbol identical.
+            /// Coins may have multiple IDs if both name and symbol identical, the primary [Coin::id] comes first.
             /// ```
+            /// # #[cfg(feature = "full")] {
             /// use nil_slip44::Coin;
             ///
-            /// assert_eq!(Coin::Credits.ids(), vec![334, 498]);
+            /// assert_eq!(Coin::Credits.ids(), [334, 498]);
+            /// assert_eq!(Coin::Ethereum.ids(), [60]);
+            /// # }
             /// ```
-            pub fn ids(self) -> Vec<u32> { match self { $(Self::$ident => vec![$id, $($($ids),+)?], )* } }
+            pub const fn ids(self) -> &'static [u32] { match self { $(Self::$ident => &[$id, $($($ids),+)?], )* } }
 
-            /// Coin unedited name according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
+            /// Numerically smallest of [Coin::ids], whatever their order.
+            ///
+            /// Equal to [Coin::id] for every generated coin. Only a hand-written `coins!` entry listing a lower ID after
+            /// the first one makes them differ.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Credits.canonical_id(), 334);
+            /// assert_eq!(Coin::Credits.canonical_id(), Coin::Credits.id());
+            /// # }
+            /// ```
+            pub const fn canonical_id(self) -> u32 {
+                let ids = self.ids();
+                let mut canonical_id = ids[0];
+                let mut i = 1;
+                while i < ids.len() {
+                    if ids[i] < canonical_id {
+                        canonical_id = ids[i];
+                    }
+                    i += 1;
+                }
+                canonical_id
+            }
+
+            /// Verifies invariants of the registry: each coin's primary ID comes first in [Coin::ids] and belongs to
+            /// no other coin, and the coin round-trips through its primary ID and its [Symbol], if any.
+            ///
+            /// Testnet coin type 1 is exempt from the primary ID checks since distinct testnets may share it.
+            ///
+            /// Lets applications sanity-check the build they were linked against at startup.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::self_check(), Ok(()));
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn self_check() -> Result<(), $crate::CoinError> {
+                let inconsistent = |reason: String| Err($crate::CoinError::InconsistentRegistry(reason));
+                let mut primary_ids = $crate::alloc::collections::BTreeSet::new();
+
+                for coin in Self::all() {
+                    if coin.ids().first() != Some(&coin.id()) {
+                        return inconsistent(format!("{:?} doesn't list its coin type {} first", coin, coin.id()));
+                    }
+                    // Testnet coin type 1 is shared and resolves to the first testnet only.
+                    if !coin.is_testnet() {
+                        if !primary_ids.insert(coin.id()) {
+                            return inconsistent(format!("coin type {} is the primary ID of more than one coin", coin.id()));
+                        }
+                        if Self::try_from(coin.id()) != Ok(coin) {
+                            return inconsistent(format!("{:?} doesn't round-trip through coin type {}", coin, coin.id()));
+                        }
+                    }
+                    if let Ok(symbol) = Symbol::try_from(coin) {
+                        if Self::from(symbol) != coin || symbol.to_string().parse() != Ok(symbol) {
+                            return inconsistent(format!("{:?} doesn't round-trip through symbol {}", coin, symbol));
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// BIP-44 account level derivation path `m/44'/<coin_type>'/<account>'`, with hardened components marked in `style`.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::{Coin, HardenedStyle};
+            ///
+            /// assert_eq!(Coin::Bitcoin.bip44_path_styled(0, HardenedStyle::Apostrophe), "m/44'/0'/0'");
+            /// assert_eq!(Coin::Bitcoin.bip44_path_styled(0, HardenedStyle::LowerH), "m/44h/0h/0h");
+            /// assert_eq!(Coin::Ethereum.bip44_path_styled(2, HardenedStyle::UpperH), "m/44H/60H/2H");
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn bip44_path_styled(self, account: u32, style: $crate::HardenedStyle) -> String {
+                self.account_level_path('m', 44, account, style)
+            }
+
+            /// BIP-44 address path `m/44'/<coin_type>'/<account>'/<change>/<index>`, see [Coin::derivation_path].
+            ///
+            /// Coins with multiple IDs use their primary ID, see [Coin::id].
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.bip44_path(0, 0, 0), "m/44'/0'/0'/0/0");
+            /// assert_eq!(Coin::Ethereum.bip44_path(0, 0, 0), "m/44'/60'/0'/0/0");
+            /// assert_eq!(Coin::Credits.bip44_path(1, 1, 2), "m/44'/334'/1'/1/2");
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn bip44_path(self, account: u32, change: u32, index: u32) -> String {
+                self.derivation_path(44, account, change, index)
+            }
+
+            /// Address path `m/<purpose>'/<coin_type>'/<account>'/<change>/<index>`, hardened components marked with
+            /// `'`, e.g. BIP-84 paths of SegWit wallets with `purpose` 84.
+            ///
+            /// Neither `purpose` nor `change` is validated: any purpose is written as given, see
+            /// [Coin::path_for_purpose] for the checked purposes, and `change` is written as is even though BIP-44 only
+            /// uses 0 (external) and 1 (internal).
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.derivation_path(84, 0, 0, 0), "m/84'/0'/0'/0/0");
+            /// assert_eq!(Coin::Bitcoin.derivation_path(86, 1, 1, 5), "m/86'/0'/1'/1/5");
+            /// assert_eq!(Coin::Ethereum.derivation_path(44, 0, 0, 0), Coin::Ethereum.bip44_path(0, 0, 0));
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn derivation_path(self, purpose: u32, account: u32, change: u32, index: u32) -> String {
+                let account_path = self.account_level_path('m', purpose, account, $crate::HardenedStyle::Apostrophe);
+                format!("{account_path}/{change}/{index}")
+            }
+
+            /// Primary coin ID as a hardened path component, e.g. `0x8000_003c` for `60'`.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.hardened_coin_type(), 0x8000_003c);
+            /// assert_eq!(Coin::from_hardened(Coin::Ethereum.hardened_coin_type()), Some(Coin::Ethereum));
+            /// # }
+            /// ```
+            pub const fn hardened_coin_type(self) -> u32 {
+                self.id() | $crate::path::HARDENED
+            }
+
+            /// Path component of the coin according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md),
+            /// its `coin_type'` column, e.g. `0x80000000` for Bitcoin.
+            ///
+            /// Same as [Coin::hardened_coin_type]: `parse-coins` fails on any SLIP-0044 row whose path component isn't
+            /// its coin type with the hardened bit set, so the column isn't stored separately.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.hardened_path_component(), 0x80000000);
+            /// assert_eq!(Coin::Ethereum.hardened_path_component(), 0x8000003c);
+            /// # }
+            /// ```
+            pub const fn hardened_path_component(self) -> u32 {
+                self.hardened_coin_type()
+            }
+
+            /// BIP-44 account level path `M/44'/<coin_type>'/<account>'` rooted at the master public key.
+            ///
+            /// All other path helpers start at the master private key `m`; use this one only where a tool expects the
+            /// uppercase `M` of a public derivation path.
+            /// ```
+            /// use nil_slip44::{Coin, HardenedStyle};
+            ///
+            /// assert_eq!(Coin::Bitcoin.bip44_path_public(0), "M/44'/0'/0'");
+            /// assert_eq!(Coin::Bitcoin.bip44_path_styled(0, HardenedStyle::Apostrophe), "m/44'/0'/0'");
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn bip44_path_public(self, account: u32) -> String {
+                self.account_level_path('M', 44, account, $crate::HardenedStyle::Apostrophe)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn account_level_path(self, root: char, purpose: u32, account: u32, style: $crate::HardenedStyle) -> String {
+                let hardened = style.marker();
+                format!("{root}/{purpose}{hardened}/{}{hardened}/{account}{hardened}", self.id())
+            }
+
+            /// BIP-44 account path `m/44'/<coin_type>'/<account>'` as hardened integers.
+            ///
+            /// Fails with [CoinError::HardenedAccount](crate::CoinError::HardenedAccount) if `account` already has the
+            /// hardened bit set, since hardening it again would silently yield a different account.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::{Coin, CoinError};
+            ///
+            /// assert_eq!(Coin::Ethereum.account_path_checked(1), Ok(vec![0x8000_002c, 0x8000_003c, 0x8000_0001]));
+            /// assert_eq!(Coin::Ethereum.account_path_checked(0x8000_0001), Err(CoinError::HardenedAccount(0x8000_0001)));
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn account_path_checked(self, account: u32) -> Result<Vec<u32>, $crate::CoinError> {
+                let hardened = $crate::path::HARDENED;
+                if account & hardened != 0 {
+                    return Err($crate::CoinError::HardenedAccount(account));
+                }
+                Ok(vec![44 | hardened, self.id() | hardened, account | hardened])
+            }
+
+            /// [Coin::account_path_checked] for each account in `accounts`, e.g. to scan accounts during wallet
+            /// recovery. Accounts with the hardened bit set can't be derived and are left out.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(
+            ///     Coin::Ethereum.account_paths(0..2),
+            ///     vec![vec![0x8000_002c, 0x8000_003c, 0x8000_0000], vec![0x8000_002c, 0x8000_003c, 0x8000_0001]],
+            /// );
+            /// assert_eq!(Coin::Ethereum.account_paths(0x7fff_ffff..u32::MAX).len(), 1);
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn account_paths(self, accounts: core::ops::Range<u32>) -> Vec<Vec<u32>> {
+                accounts.map_while(|account| self.account_path_checked(account).ok()).collect()
+            }
+
+            /// BIP-44 address path `m/44'/<coin_type>'/<account>'/<change>/<index>` as integers, with the first three
+            /// components hardened.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.bip44_path_array(0, 0, 5), [0x8000_002c, 0x8000_003c, 0x8000_0000, 0, 5]);
+            /// # }
+            /// ```
+            pub const fn bip44_path_array(self, account: u32, change: u32, index: u32) -> [u32; 5] {
+                let hardened = $crate::path::HARDENED;
+                [44 | hardened, self.id() | hardened, account | hardened, change, index]
+            }
+
+            /// Components of [Coin::bip44_path_array], yielded without allocating.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// let components: Vec<_> = Coin::Bitcoin.bip44_components(1, 1, 7).collect();
+            /// assert_eq!(components, Coin::Bitcoin.bip44_path_array(1, 1, 7));
+            /// ```
+            pub fn bip44_components(self, account: u32, change: u32, index: u32) -> impl Iterator<Item = u32> {
+                self.bip44_path_array(account, change, index).into_iter()
+            }
+
+            /// Coin of a hardened coin type path component, e.g. `0x8000_003c` for `60'`.
+            ///
+            /// Returns `None` if `component` isn't hardened or the coin type is unknown.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_hardened(0x8000_003c), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_hardened(60), None);
+            /// # }
+            /// ```
+            pub fn from_hardened(component: u32) -> Option<Self> {
+                let hardened = $crate::path::HARDENED;
+                if component & hardened == 0 {
+                    return None;
+                }
+                Self::try_from(component & !hardened).ok()
+            }
+
+            /// Coin of a BIP-44 derivation path given as integers, i.e. starting with `44'` followed by a hardened
+            /// coin type, see [Coin::from_hardened].
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_path_components(&Coin::Bitcoin.bip44_path_array(0, 0, 0)), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_path_components(&[0x8000_002c, 0x8000_0000]), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_path_components(&[0x8000_002c]), None);
+            /// assert_eq!(Coin::from_path_components(&[0x8000_0054, 0x8000_0000]), None);
+            /// ```
+            pub fn from_path_components(components: &[u32]) -> Option<Self> {
+                match components {
+                    [purpose, coin_type, ..] if *purpose == 44 | $crate::path::HARDENED => Self::from_hardened(*coin_type),
+                    _ => None,
+                }
+            }
+
+            /// Account level derivation path `m/<purpose>'/<coin_type>'/<account>'` with all components hardened.
+            ///
+            /// `purpose` must be one of 44 (BIP-44), 49 (BIP-49), 84 (BIP-84) or 86 (BIP-86).
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::{Coin, CoinError};
+            ///
+            /// assert_eq!(Coin::Bitcoin.path_for_purpose(84, 0), Ok(vec![0x8000_0054, 0x8000_0000, 0x8000_0000]));
+            /// assert_eq!(Coin::Ethereum.path_for_purpose(44, 1), Ok(vec![0x8000_002c, 0x8000_003c, 0x8000_0001]));
+            /// assert_eq!(Coin::Bitcoin.path_for_purpose(45, 0), Err(CoinError::UnknownPurpose(45)));
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn path_for_purpose(self, purpose: u32, account: u32) -> Result<Vec<u32>, $crate::CoinError> {
+                if !$crate::path::PURPOSES.contains(&purpose) {
+                    return Err($crate::CoinError::UnknownPurpose(purpose));
+                }
+                let hardened = $crate::path::HARDENED;
+                Ok(vec![purpose | hardened, self.id() | hardened, account | hardened])
+            }
+
+            /// Address level derivation path `m/<purpose>'/<coin_type>'/<account>'/<change>/<index>` as a
+            /// [coins-bip32](https://docs.rs/coins-bip32) path.
+            ///
+            /// Fails like [Coin::path_for_purpose] on an unknown `purpose` and like [Coin::account_path_checked] on an
+            /// already hardened `account`.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use coins_bip32::path::DerivationPath;
+            /// use nil_slip44::{Coin, CoinError};
+            ///
+            /// assert_eq!(Coin::Bitcoin.coins_bip32_path(84, 0, 0, 1), Ok("m/84'/0'/0'/0/1".parse::<DerivationPath>().unwrap()));
+            /// assert_eq!(Coin::Ethereum.coins_bip32_path(44, 0, 0, 0), Ok("m/44'/60'/0'/0/0".parse::<DerivationPath>().unwrap()));
+            /// assert_eq!(Coin::Ethereum.coins_bip32_path(45, 0, 0, 0), Err(CoinError::UnknownPurpose(45)));
+            /// # }
+            /// ```
+            #[cfg(feature = "bip32")]
+            pub fn coins_bip32_path(
+                self,
+                purpose: u32,
+                account: u32,
+                change: u32,
+                index: u32,
+            ) -> Result<coins_bip32::path::DerivationPath, $crate::CoinError> {
+                if account & $crate::path::HARDENED != 0 {
+                    return Err($crate::CoinError::HardenedAccount(account));
+                }
+                let mut path = self.path_for_purpose(purpose, account)?;
+                path.extend([change, index]);
+                Ok(path.into())
+            }
+
+            /// Coin of a [coins-bip32](https://docs.rs/coins-bip32) path starting with a hardened BIP-44, BIP-49,
+            /// BIP-84 or BIP-86 purpose followed by a hardened coin type, the inverse of [Coin::coins_bip32_path].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use coins_bip32::path::DerivationPath;
+            /// use nil_slip44::Coin;
+            ///
+            /// let path: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+            /// assert_eq!(Coin::from_coins_bip32_path(&path), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_coins_bip32_path(&"m/86'/0'".parse().unwrap()), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_coins_bip32_path(&"m/44/60".parse().unwrap()), None);
+            /// assert_eq!(Coin::from_coins_bip32_path(&"m/0'/60'".parse().unwrap()), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "bip32")]
+            pub fn from_coins_bip32_path(path: &coins_bip32::path::DerivationPath) -> Option<Self> {
+                let hardened = $crate::path::HARDENED;
+                match path.iter().as_slice() {
+                    [purpose, coin_type, ..]
+                        if purpose & hardened != 0
+                            && coin_type & hardened != 0
+                            && $crate::path::PURPOSES.contains(&(purpose & !hardened)) =>
+                    {
+                        Self::try_from(coin_type & !hardened).ok()
+                    }
+                    _ => None,
+                }
+            }
+
+            /// [alloy](https://docs.rs/alloy-chains) chain of an EVM-compatible coin.
+            ///
+            /// Returns `None` for coins that aren't EVM-compatible according to the crate-curated list.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use alloy_chains::{Chain, NamedChain};
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.alloy_chain(), Some(Chain::mainnet()));
+            /// assert_eq!(Coin::Arbitrum.alloy_chain(), Some(Chain::from_named(NamedChain::Arbitrum)));
+            /// assert_eq!(Coin::Bitcoin.alloy_chain(), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "alloy")]
+            pub fn alloy_chain(self) -> Option<alloy_chains::Chain> {
+                $crate::overlay::lookup($crate::overlay::EVM_CHAIN_IDS, self.id()).map(alloy_chains::Chain::from_id)
+            }
+
+            /// Coin of an [alloy](https://docs.rs/alloy-chains) chain, the inverse of [Coin::alloy_chain].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use alloy_chains::Chain;
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_alloy_chain(Chain::mainnet()), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_alloy_chain(Coin::Matic.alloy_chain().unwrap()), Some(Coin::Matic));
+            /// assert_eq!(Coin::from_alloy_chain(Chain::from_id(31337)), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "alloy")]
+            pub fn from_alloy_chain(chain: alloy_chains::Chain) -> Option<Self> {
+                $crate::overlay::EVM_CHAIN_IDS
+                    .iter()
+                    .find(|(_, chain_id)| *chain_id == chain.id())
+                    .and_then(|(coin_type, _)| Self::try_from(*coin_type).ok())
+            }
+
+            /// [CAIP-2](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-2.md) namespace of the coin's chain,
+            /// e.g. `bip122` for Bitcoin or `eip155` for EVM-compatible chains.
+            ///
+            /// Comes from a crate-curated list, not from SLIP-0044. Returns `None` for coins without a known namespace.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.caip2_namespace(), Some("bip122"));
+            /// assert_eq!(Coin::Ethereum.caip2_namespace(), Some("eip155"));
+            /// assert_eq!(Coin::Solana.caip2_namespace(), Some("solana"));
+            /// assert_eq!(Coin::OpenAssets.caip2_namespace(), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "caip")]
+            pub const fn caip2_namespace(self) -> Option<&'static str> {
+                match $crate::overlay::lookup($crate::overlay::CAIP2_NAMESPACES, self.id()) {
+                    Some(namespace) => Some(namespace),
+                    None if $crate::overlay::lookup($crate::overlay::EVM_CHAIN_IDS, self.id()).is_some() => Some("eip155"),
+                    None => None,
+                }
+            }
+
+            /// [CAIP-19](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-19.md) asset ID of the native
+            /// coin, `<CAIP-2 chain ID>/slip44:<coin type>`.
+            ///
+            /// Returns `None` for coins without a CAIP-2 chain ID in the crate-curated list.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.caip19_native().as_deref(), Some("eip155:1/slip44:60"));
+            /// assert_eq!(
+            ///     Coin::Bitcoin.caip19_native().as_deref(),
+            ///     Some("bip122:000000000019d6689c085ae165831e93/slip44:0"),
+            /// );
+            /// assert_eq!(Coin::Matic.caip19_native().as_deref(), Some("eip155:137/slip44:966"));
+            /// assert_eq!(Coin::OpenAssets.caip19_native(), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "caip")]
+            pub fn caip19_native(self) -> Option<String> {
+                let chain = match $crate::overlay::lookup($crate::overlay::CAIP2_CHAIN_IDS, self.id()) {
+                    Some(chain) => chain.to_string(),
+                    None => format!("eip155:{}", $crate::overlay::lookup($crate::overlay::EVM_CHAIN_IDS, self.id())?),
+                };
+                Some(format!("{}/slip44:{}", chain, self.id()))
+            }
+
+            /// Checks an externally sourced `id` to `symbol` mapping against the registry.
+            ///
+            /// Fails with [CoinError::SymbolMismatch](crate::CoinError::SymbolMismatch) if the coin with that ID has a
+            /// different symbol (compared case-insensitively, duplicate symbols included) or none at all. Unknown IDs
+            /// aren't contradicted by the registry and pass.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::{Coin, CoinError};
+            ///
+            /// assert_eq!(Coin::check_external_mapping(60, "eth"), Ok(()));
+            /// assert_eq!(Coin::check_external_mapping(337, "CPC"), Ok(()));
+            /// assert_eq!(
+            ///     Coin::check_external_mapping(60, "ETC"),
+            ///     Err(CoinError::SymbolMismatch { id: 60, expected: Some("ETH"), found: "ETC".to_string() }),
+            /// );
+            /// assert_eq!(Coin::check_external_mapping(2147483647, "ETH"), Ok(()));
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn check_external_mapping(id: u32, symbol: &str) -> Result<(), $crate::CoinError> {
+                match Self::try_from(id) {
+                    Ok(coin) if !coin.raw_symbol().is_some_and(|ticker| ticker.eq_ignore_ascii_case(symbol)) => {
+                        Err($crate::CoinError::SymbolMismatch { id, expected: coin.raw_symbol(), found: symbol.to_string() })
+                    }
+                    _ => Ok(()),
+                }
+            }
+
+            /// Coin ID as big-endian bytes, as carried by binary protocols.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.coin_type_be_bytes(), [0, 0, 0, 60]);
+            /// # }
+            /// ```
+            pub const fn coin_type_be_bytes(self) -> [u8; 4] { self.id().to_be_bytes() }
+
+            /// Coin with the ID encoded as big-endian bytes, the inverse of [Coin::coin_type_be_bytes].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_be_bytes(Coin::Ethereum.coin_type_be_bytes()), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_be_bytes([0x7f, 0xff, 0xff, 0xff]), None);
+            /// # }
+            /// ```
+            pub fn from_be_bytes(bytes: [u8; 4]) -> Option<Self> {
+                Self::try_from(u32::from_be_bytes(bytes)).ok()
+            }
+
+            /// Hash identifying the coin across crate versions, unlike its position in [Coin::all].
+            ///
+            /// Computed as the 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of
+            /// [Coin::coin_type_be_bytes] followed by the UTF-8 bytes of [Coin::as_variant_str], so it only changes if
+            /// the coin's primary ID or variant name does.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.stable_hash(), 0xc1fa_7467_ca30_efcd);
+            /// assert_eq!(Coin::Ethereum.stable_hash(), 0xaeea_195f_baba_52cc);
+            /// # }
+            /// ```
+            pub const fn stable_hash(self) -> u64 {
+                let hash = $crate::hash::fnv1a($crate::hash::FNV_OFFSET_BASIS, &self.coin_type_be_bytes());
+                $crate::hash::fnv1a(hash, self.as_variant_str().as_bytes())
+            }
+
+            /// Boolean properties of the coin packed into [CoinFlags](crate::CoinFlags).
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::{Coin, CoinFlags};
+            ///
+            /// assert_eq!(Coin::Bitcoin.flags(), CoinFlags::HAS_SYMBOL);
+            /// assert_eq!(Coin::Ethereum.flags(), CoinFlags::HAS_SYMBOL | CoinFlags::IS_EVM);
+            /// assert_eq!(Coin::Testnet.flags(), CoinFlags::IS_TESTNET);
+            /// assert!(Coin::Credits.flags().is_multi_id());
+            /// # }
+            /// ```
+            pub const fn flags(self) -> $crate::CoinFlags {
+                let mut flags = $crate::CoinFlags::empty();
+                if self.raw_symbol().is_some() {
+                    flags = flags.union($crate::CoinFlags::HAS_SYMBOL);
+                }
+                if self.is_testnet() {
+                    flags = flags.union($crate::CoinFlags::IS_TESTNET);
+                }
+                if self.is_evm() {
+                    flags = flags.union($crate::CoinFlags::IS_EVM);
+                }
+                if self.is_multi_id() {
+                    flags = flags.union($crate::CoinFlags::IS_MULTI_ID);
+                }
+                flags
+            }
+
+            /// Whether the coin supports SegWit, for Bitcoin-family coins only.
+            ///
+            /// Returns `None` for coins outside the Bitcoin family. This is curated by the crate and not part of
+            /// [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md).
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.supports_segwit(), Some(true));
+            /// assert_eq!(Coin::BitcoinCash.supports_segwit(), Some(false));
+            /// assert_eq!(Coin::Ethereum.supports_segwit(), None);
+            /// # }
+            /// ```
+            pub const fn supports_segwit(self) -> Option<bool> {
+                $crate::overlay::lookup($crate::overlay::SEGWIT_SUPPORT, self.id())
+            }
+
+            /// Whether the chain natively hosts a major stablecoin (USDT or USDC).
+            ///
+            /// This is an advisory, hand-curated classification maintained by the crate; it's not part of
+            /// [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) and can lag behind reality.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::Ethereum.hosts_major_stablecoins());
+            /// assert!(Coin::Tron.hosts_major_stablecoins());
+            /// assert!(!Coin::Bitcoin.hosts_major_stablecoins());
+            /// # }
+            /// ```
+            #[cfg(feature = "stablecoins")]
+            pub const fn hosts_major_stablecoins(self) -> bool {
+                $crate::overlay::contains($crate::overlay::MAJOR_STABLECOIN_HOSTS, self.id())
+            }
+
+            /// Coin that a wrapped or pegged coin tracks, according to a small crate-curated list.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::LiquidBTC.underlying(), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::WrappedSafexCash.underlying(), Some(Coin::SafexCash));
+            /// assert_eq!(Coin::Bitcoin.underlying(), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "relations")]
+            pub fn underlying(self) -> Option<Self> {
+                $crate::overlay::lookup($crate::overlay::UNDERLYING, self.id()).and_then(|id| Self::try_from(id).ok())
+            }
+
+            /// Whether the coin has coin type 1, which SLIP-0044 reserves for the testnets of all coins.
+            ///
+            /// Rows SLIP-0044 marks as `reserved` are skipped by `parse-coins`, so no [Coin] stands for a reserved coin
+            /// type.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::Testnet.is_testnet());
+            /// assert!(!Coin::Bitcoin.is_testnet());
+            /// ```
+            pub const fn is_testnet(self) -> bool { self.id() == $crate::path::TESTNET_COIN_TYPE }
+
+            /// Whether the coin is an EVM-compatible chain, according to a list of chains curated by this crate rather
+            /// than SLIP-0044, which has no such data.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::Ethereum.is_evm());
+            /// assert!(Coin::EthereumClassic.is_evm());
+            /// assert!(Coin::Matic.is_evm());
+            /// assert!(!Coin::Bitcoin.is_evm());
+            /// # }
+            /// ```
+            pub const fn is_evm(self) -> bool { $crate::overlay::lookup($crate::overlay::EVM_CHAIN_IDS, self.id()).is_some() }
+
+            const fn is_multi_id(self) -> bool { match self { $(Self::$ident => [$id, $($($ids),+)?].len() > 1, )* } }
+
+            /// Coin with the given ID, primary or secondary, looked up in [Coin::ID_INDEX].
+            ///
+            /// If several coins claim the ID, the one with the lowest coin type wins.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_id(0), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_id(60), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_id(334), Some(Coin::Credits));
+            /// assert_eq!(Coin::from_id(498), Some(Coin::Credits));
+            /// assert_eq!(Coin::from_id(2147483647), None);
+            /// # }
+            /// ```
+            pub fn from_id(id: u32) -> Option<Self> {
+                let position = Self::ID_INDEX.partition_point(|(other, _)| *other < id);
+                Self::ID_INDEX.get(position).filter(|(other, _)| *other == id).map(|(_, coin)| *coin)
+            }
+
+            /// Coin with the given ID, or `default` if the ID is unknown.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_id_or(60, Coin::Testnet), Coin::Ethereum);
+            /// assert_eq!(Coin::from_id_or(2147483647, Coin::Testnet), Coin::Testnet);
+            /// # }
+            /// ```
+            pub fn from_id_or(id: u32, default: Self) -> Self {
+                Self::try_from(id).unwrap_or(default)
+            }
+
+            /// Every coin ID mapped to its coin like [Coin::from_id] does, built on first access.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::id_map().get(&498), Some(&Coin::Credits));
+            /// assert_eq!(Coin::id_map().len(), Coin::ID_INDEX.len());
+            /// # }
+            /// ```
+            #[cfg(feature = "lazy-maps")]
+            pub fn id_map() -> &'static std::collections::HashMap<u32, Self> {
+                static BY_ID: std::sync::OnceLock<std::collections::HashMap<u32, Coin>> = std::sync::OnceLock::new();
+
+                BY_ID.get_or_init(|| {
+                    let mut coins = std::collections::HashMap::with_capacity(Self::ID_INDEX.len());
+                    for &(id, coin) in Self::ID_INDEX {
+                        coins.entry(id).or_insert(coin);
+                    }
+                    coins
+                })
+            }
+
+            /// Coin for a number of unknown interpretation, trying in order:
+            ///
+            /// 1. a coin type, as in [Coin::try_from];
+            /// 2. a hardened coin type, i.e. a coin type with `0x8000_0000` added as found in derivation paths;
+            /// 3. with the `evm` feature, an [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain ID of an
+            ///    EVM-compatible coin.
+            ///
+            /// The first interpretation that matches wins, so e.g. `1` is the testnet coin type rather than Ethereum
+            /// mainnet's chain ID.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::resolve_numeric(60), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::resolve_numeric(0x8000_003c), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::resolve_numeric(1), Some(Coin::Testnet));
+            /// assert_eq!(Coin::resolve_numeric(137), Some(Coin::Rootstock));
+            /// # #[cfg(feature = "evm")]
+            /// assert_eq!(Coin::resolve_numeric(42161), Some(Coin::Arbitrum));
+            /// # #[cfg(not(feature = "evm"))]
+            /// assert_eq!(Coin::resolve_numeric(42161), None);
+            /// assert_eq!(Coin::resolve_numeric(u64::MAX), None);
+            /// # }
+            /// ```
+            pub fn resolve_numeric(n: u64) -> Option<Self> {
+                let hardened = u64::from($crate::path::HARDENED);
+                let coin = u32::try_from(n).ok().and_then(|id| Self::try_from(id).ok());
+                let coin = coin.or_else(|| {
+                    n.checked_sub(hardened)
+                        .filter(|&id| id < hardened)
+                        .and_then(|id| Self::try_from(id as u32).ok())
+                });
+                #[cfg(feature = "evm")]
+                let coin = coin.or_else(|| {
+                    $crate::overlay::EVM_CHAIN_IDS
+                        .iter()
+                        .find(|(_, chain_id)| *chain_id == n)
+                        .and_then(|(coin_type, _)| Self::try_from(*coin_type).ok())
+                });
+                coin
+            }
+
+            /// Coin symbol linked to [Symbol], or `None` if the coin has no symbol or only a
+            /// [duplicate](Coin::duplicate_symbol) one.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.symbol(), Some("BTC"));
+            /// assert_eq!(Coin::OpenAssets.symbol(), None);
+            /// assert_eq!(Coin::CPChain.symbol(), None);
+            /// # }
+            /// ```
+            pub const fn symbol(self) -> Option<&'static str> {
+                match self { $(Self::$ident => $crate::coins!(@symbol $($symbol)?), )* }
+            }
+
+            /// Coin whose [Coin::symbol] is exactly `symbol`.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_symbol("BTC"), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_symbol("CPC"), Some(Coin::Capricoin));
+            /// assert_eq!(Coin::from_symbol("btc"), None);
+            /// # }
+            /// ```
+            pub fn from_symbol(symbol: &str) -> Option<Self> {
+                symbol.parse::<Symbol>().ok().map(Self::from)
+            }
+
+            /// Every coin with the exact symbol `symbol`, duplicate symbols (see [Coin::duplicate_symbol]) included, in
+            /// coin type order.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::coins_for_symbol("CPC").collect::<Vec<_>>(), vec![Coin::Capricoin, Coin::CPChain]);
+            /// assert_eq!(Coin::coins_for_symbol("BTC").collect::<Vec<_>>(), vec![Coin::Bitcoin]);
+            /// assert_eq!(Coin::coins_for_symbol("btc").count(), 0);
+            /// # }
+            /// ```
+            pub fn coins_for_symbol(symbol: &str) -> impl Iterator<Item = Self> + '_ {
+                Self::all().filter(move |coin| coin.raw_symbol() == Some(symbol))
+            }
+
+            /// Coin whose [Coin::symbol] equals `symbol` ignoring case.
+            ///
+            /// Lookups after the first one are hash map lookups. Should two symbols only differ in case, an exact
+            /// match wins, and otherwise the coin with the lowest coin type.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_symbol_ci("btc"), Some(Coin::Bitcoin));
+            /// assert_eq!(Coin::from_symbol_ci("kusd"), Some(Coin::kUSD));
+            /// assert_eq!(Coin::from_symbol_ci("NOPE"), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "std")]
+            pub fn from_symbol_ci(symbol: &str) -> Option<Self> {
+                static BY_UPPERCASE_SYMBOL: std::sync::OnceLock<std::collections::HashMap<String, Coin>> =
+                    std::sync::OnceLock::new();
+
+                Self::from_symbol(symbol).or_else(|| {
+                    BY_UPPERCASE_SYMBOL
+                        .get_or_init(|| {
+                            let mut coins = std::collections::HashMap::new();
+                            for coin in Self::all() {
+                                if let Some(symbol) = coin.symbol() {
+                                    coins.entry(symbol.to_uppercase()).or_insert(coin);
+                                }
+                            }
+                            coins
+                        })
+                        .get(&symbol.to_uppercase())
+                        .copied()
+                })
+            }
+
+            /// Every [Coin::symbol] with its coin, ordered by coin type. Coins without a symbol, or with only a
+            /// [duplicate](Coin::duplicate_symbol) one, are skipped, so each symbol appears once.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::iter_symbols().next(), Some(("BTC", Coin::Bitcoin)));
+            /// assert!(Coin::iter_symbols().all(|(_, coin)| coin != Coin::OpenAssets && coin != Coin::CPChain));
+            /// # }
+            /// ```
+            pub fn iter_symbols() -> impl Iterator<Item = (&'static str, Self)> {
+                Self::all().filter_map(|coin| Some((coin.symbol()?, coin)))
+            }
+
+            /// Every [Coin::symbol] mapped to its coin like [Coin::from_symbol] does, built on first access.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::symbol_map().get("CPC"), Some(&Coin::Capricoin));
+            /// assert_eq!(Coin::symbol_map().get("btc"), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "lazy-maps")]
+            pub fn symbol_map() -> &'static std::collections::HashMap<&'static str, Self> {
+                static BY_SYMBOL: std::sync::OnceLock<std::collections::HashMap<&'static str, Coin>> =
+                    std::sync::OnceLock::new();
+
+                BY_SYMBOL.get_or_init(|| Self::all().filter_map(|coin| Some((coin.symbol()?, coin))).collect())
+            }
+
+            /// Short name of the coin, i.e. the name of its [Coin] variant, see [Coin::coin_name] for the unedited one.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::UniformFiscalObject.name(), "UniformFiscalObject");
+            /// assert_eq!(Coin::Ethereum.name(), "Ethereum");
+            /// # }
+            /// ```
+            pub const fn name(self) -> &'static str { self.as_variant_str() }
+
+            /// Coin by its exact [Coin::name], the inverse of it. Same as [Coin::from_variant_str].
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_name("Ethereum"), Some(Coin::Ethereum));
+            /// assert_eq!(Coin::from_name("ethereum"), None);
+            /// assert_eq!(Coin::from_name("Ether"), None);
+            /// # }
+            /// ```
+            pub fn from_name(name: &str) -> Option<Self> { Self::from_variant_str(name) }
+
+            /// Every [Coin::name] with its coin, ordered by coin type.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::iter_names().next(), Some(("Bitcoin", Coin::Bitcoin)));
+            /// assert_eq!(Coin::iter_names().count(), Coin::ALL.len());
+            /// ```
+            pub fn iter_names() -> impl Iterator<Item = (&'static str, Self)> {
+                Self::all().map(|coin| (coin.name(), coin))
+            }
+
+            /// Coin unedited name according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::UniformFiscalObject.coin_name(), "Uniform Fiscal Object");
+            /// assert_eq!(Coin::Ethereum.coin_name(), "Ether");
+            /// # }
+            /// ```
+            pub const fn coin_name(self) -> &'static str { match self { $(Self::$ident => $name, )* } }
+
+            /// [Coin::coin_name] cut to at most `max` characters, the last of them a `…` if the name was cut.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::UniformFiscalObject.name_truncated(10), "Uniform F…");
+            /// assert_eq!(Coin::Bitcoin.name_truncated(10), "Bitcoin");
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn name_truncated(self, max: usize) -> String {
+                let name = self.coin_name();
+                match name.char_indices().nth(max) {
+                    None => name.to_string(),
+                    Some(_) => match max.checked_sub(1).and_then(|last| name.char_indices().nth(last)) {
+                        Some((end, _)) => format!("{}…", &name[..end]),
+                        None => String::new(),
+                    },
+                }
+            }
+
+            /// Name of the [Coin] variant itself.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::UniformFiscalObject.as_variant_str(), "UniformFiscalObject");
+            /// # }
+            /// ```
+            pub const fn as_variant_str(self) -> &'static str { match self { $(Self::$ident => stringify!($ident), )* } }
+
+            /// Coin by the exact name of its [Coin] variant, as returned by [Coin::as_variant_str].
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::from_variant_str("UniformFiscalObject"), Some(Coin::UniformFiscalObject));
+            /// assert_eq!(Coin::from_variant_str("uniformfiscalobject"), None);
+            /// assert_eq!(Coin::from_variant_str("Uniform Fiscal Object"), None);
+            /// # }
+            /// ```
+            pub fn from_variant_str(s: &str) -> Option<Self> {
+                match s {
+                    $(stringify!($ident) => Some(Self::$ident), )*
+                    _ => None
+                }
+            }
+
+            /// Coin symbol that's not included into [Symbol] enum due to being a duplicate of another coin by symbol name.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::CPChain.duplicate_symbol(), Some("CPC".to_string()));
+            /// # }
+            /// ```
+            /// Such conflicts are resolved by taking only first coin (ordered by id) into [Symbol] enum
+            /// (e.g. both [Coin::CPChain] and [Coin::Capricoin] has symbol "CPC" but only [Coin::Capricoin] is eligible to be linked to [Symbol::CPC] since
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::Capricoin.id() < Coin::CPChain.id());
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn duplicate_symbol(self) -> Option<String> {
+                match self {
+                    $($(Self::$ident => Some($duplicate_symbol.to_string()), )?)*
+                    _ => None
+                }
+            }
+
+            /// URL the coin name links to in [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md),
+            /// e.g. the project page, or `None` if the name isn't a link. SLIP-0044 links few names, if any.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::OpenAssets.homepage(), None);
+            /// # }
+            /// ```
+            pub const fn homepage(self) -> Option<&'static str> {
+                match self {
+                    $($(Self::$ident => Some($homepage), )?)*
+                    _ => None
+                }
+            }
+
+            /// Coins similar to `query`, with a score from 0.0 (exclusive) to 1.0 for an exact match, best first.
+            ///
+            /// The score is the best normalized edit distance similarity of `query` to the coin symbol or [Coin::coin_name],
+            /// ignoring case. Coins with the same score are ordered by coin type.
+            /// ```
+            /// use nil_slip44::Coin;
+            ///
+            /// let scores = Coin::fuzzy_with_scores("BTC");
+            /// assert_eq!(scores[0], (Coin::Bitcoin, 1.0));
+            ///
+            /// let (_, score) = Coin::fuzzy_with_scores("bitcoi").into_iter().find(|(coin, _)| *coin == Coin::Bitcoin).unwrap();
+            /// assert!(0.0 < score && score < 1.0);
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn fuzzy_with_scores(query: &str) -> Vec<(Self, f32)> {
+                let mut scores: Vec<_> = Self::all()
+                    .map(|coin| {
+                        let name = $crate::search::similarity(query, coin.coin_name());
+                        let symbol = coin.raw_symbol().map_or(0.0, |ticker| $crate::search::similarity(query, ticker));
+                        (coin, name.max(symbol))
+                    })
+                    .filter(|(_, score)| *score > 0.0)
+                    .collect();
+                scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+                scores
+            }
+
+            /// Coins whose symbol, [variant name](Coin::name) or [Coin::coin_name] contains `query`, ignoring case, for
+            /// type-ahead.
+            ///
+            /// Coins where any of them starts with `query` come first, then the others, each ordered by coin type.
+            /// An empty `query` matches every coin; slice the result to cap it.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// let coins = Coin::search("bit");
+            /// assert_eq!(coins[0], Coin::Bitcoin);
+            /// assert!(coins.contains(&Coin::BitcoinCash));
+            ///
+            /// // "Coinevo" starts with "coin", "Bitcoin" only contains it.
+            /// let coins = Coin::search("coin");
+            /// let coinevo = coins.iter().position(|&coin| coin == Coin::Coinevo).unwrap();
+            /// let bitcoin = coins.iter().position(|&coin| coin == Coin::Bitcoin).unwrap();
+            /// assert!(coinevo < bitcoin);
+            /// assert!(Coin::search("no such coin").is_empty());
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn search(query: &str) -> Vec<Self> {
+                let query = query.to_lowercase();
+                let mut coins: Vec<_> = Self::all()
+                    .filter_map(|coin| {
+                        [coin.raw_symbol(), Some(coin.name()), Some(coin.coin_name())]
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|field| $crate::search::match_rank(&query, field))
+                            .min()
+                            .map(|rank| (rank, coin))
+                    })
+                    .collect();
+                coins.sort_by_key(|&(rank, coin)| (rank, coin.id()));
+                coins.into_iter().map(|(_, coin)| coin).collect()
+            }
+
+            /// Up to `n` coins whose symbols are closest to `query` by edit distance, ignoring case.
+            ///
+            /// Coins at the same distance are ordered by coin type; coins without a symbol are never suggested.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::symbol_suggestions("btcq", 5)[0], Coin::Bitcoin);
+            /// assert!(Coin::symbol_suggestions("ETJ", 3).contains(&Coin::Ethereum));
+            /// assert!(Coin::symbol_suggestions("ETH", 0).is_empty());
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn symbol_suggestions(query: &str, n: usize) -> Vec<Self> {
+                let query = query.to_uppercase();
+                let mut coins: Vec<_> = Self::all()
+                    .filter_map(|coin| {
+                        coin.raw_symbol()
+                            .map(|ticker| ($crate::search::levenshtein(&query, &ticker.to_uppercase()), coin))
+                    })
+                    .collect();
+                coins.sort_by_key(|(distance, _)| *distance);
+                coins.into_iter().take(n).map(|(_, coin)| coin).collect()
+            }
+
+            /// Resolves each of `symbols` to a coin, ignoring case, keyed by the symbol as given.
+            ///
+            /// A symbol shared by several coins resolves to the one with the lowest coin type. Unknown symbols map to `None`.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// let coins = Coin::resolve_symbols(&["btc", "ETH", "Cpc", "NOPE"]);
+            /// assert_eq!(coins.len(), 4);
+            /// assert_eq!(coins["btc"], Some(Coin::Bitcoin));
+            /// assert_eq!(coins["ETH"], Some(Coin::Ethereum));
+            /// assert_eq!(coins["Cpc"], Some(Coin::Capricoin));
+            /// assert_eq!(coins["NOPE"], None);
+            /// # }
+            /// ```
+            #[cfg(feature = "std")]
+            pub fn resolve_symbols(symbols: &[&str]) -> std::collections::HashMap<String, Option<Self>> {
+                let mut index = std::collections::HashMap::new();
+                for coin in Self::all() {
+                    if let Some(ticker) = coin.raw_symbol() {
+                        index.entry(ticker.to_uppercase()).or_insert(coin);
+                    }
+                }
+
+                symbols
+                    .iter()
+                    .map(|symbol| (symbol.to_string(), index.get(&symbol.to_uppercase()).copied()))
+                    .collect()
+            }
+
+            /// Coins whose symbol appears as a whole word in `text`, ignoring case, ordered by coin type.
+            ///
+            /// Words are runs of alphanumeric characters, so `"BTC"` is found in `"send 1 BTC now"` but not in `"BTCX"`.
+            /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::coins_with_symbol_in("send 1 BTC now").contains(&Coin::Bitcoin));
+            /// assert!(Coin::coins_with_symbol_in("Wrapped btc/eth").contains(&Coin::Ethereum));
+            /// assert!(!Coin::coins_with_symbol_in("BTCX").contains(&Coin::Bitcoin));
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn coins_with_symbol_in(text: &str) -> Vec<Self> {
+                let words: $crate::alloc::collections::BTreeSet<_> = text
+                    .split(|ch: char| !ch.is_alphanumeric())
+                    .filter(|word| !word.is_empty())
+                    .map(str::to_uppercase)
+                    .collect();
+
+                Self::all()
+                    .filter(|coin| coin.raw_symbol().is_some_and(|ticker| words.contains(&ticker.to_uppercase())))
+                    .collect()
+            }
+
+            /// Every distinct coin symbol, duplicate symbols (see [Coin::duplicate_symbol]) included, sorted
+            /// lexicographically.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// let symbols = Coin::all_symbols_sorted();
+            /// assert!(symbols.windows(2).all(|pair| pair[0] < pair[1]));
+            /// assert!(symbols.contains(&"BTC") && symbols.contains(&"ETH"));
+            /// assert_eq!(symbols.iter().filter(|&&symbol| symbol == "CPC").count(), 1);
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn all_symbols_sorted() -> Vec<&'static str> {
+                let mut symbols: Vec<_> = Self::all().filter_map(Self::raw_symbol).collect();
+                symbols.sort_unstable();
+                symbols.dedup();
+                symbols
+            }
+
+            /// Ticker conventions met by the coin symbol, duplicate symbols (see [Coin::duplicate_symbol]) included,
+            /// or `None` if the coin has no symbol.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::Bitcoin.symbol_quality().unwrap().passes());
+            /// assert!(!Coin::_8Bit.symbol_quality().unwrap().is_alphanumeric);
+            /// assert_eq!(Coin::OpenAssets.symbol_quality(), None);
+            /// # }
+            /// ```
+            pub fn symbol_quality(self) -> Option<$crate::SymbolQuality> {
+                self.raw_symbol().map($crate::SymbolQuality::of)
+            }
+
+            /// Length of the coin symbol in bytes, or `None` if the coin has no symbol.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.symbol_len(), Some(3));
+            /// assert_eq!(Coin::Dogecoin.symbol_len(), Some(4));
+            /// assert_eq!(Coin::OpenAssets.symbol_len(), None);
+            /// # }
+            /// ```
+            pub const fn symbol_len(self) -> Option<usize> {
+                match self.raw_symbol() {
+                    Some(ticker) => Some(ticker.len()),
+                    None => None,
+                }
+            }
+
+            /// Length of the coin symbol in extended grapheme clusters, i.e. user-perceived characters, or `None` if
+            /// the coin has no symbol. Equals [Coin::symbol_len] for ASCII symbols.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Dogecoin.symbol_grapheme_count(), Some(4));
+            /// assert_eq!(Coin::OpenAssets.symbol_grapheme_count(), None);
+            /// # }
+            /// ```
+            #[cfg(feature = "unicode")]
+            pub fn symbol_grapheme_count(self) -> Option<usize> {
+                use unicode_segmentation::UnicodeSegmentation;
+
+                self.raw_symbol().map(|ticker| ticker.graphemes(true).count())
+            }
+
+            /// [Coin::symbol_or_variant] without a wrapper prefix, see [base_symbol](crate::base_symbol).
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::WrappedSafexCash.base_symbol(), "SFX");
+            /// assert_eq!(Coin::Waykichain.base_symbol(), "WICC");
+            /// # }
+            /// ```
+            pub fn base_symbol(self) -> &'static str {
+                $crate::base_symbol(self.symbol_or_variant())
+            }
+
+            /// Coin symbol, or the name of its [Coin] variant if the coin has no symbol.
+            ///
+            /// Duplicate symbols (see [Coin::duplicate_symbol]) are used as is.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Bitcoin.symbol_or_variant(), "BTC");
+            /// assert_eq!(Coin::CPChain.symbol_or_variant(), "CPC");
+            /// assert_eq!(Coin::OpenAssets.symbol_or_variant(), "OpenAssets");
+            /// # }
+            /// ```
+            pub const fn symbol_or_variant(self) -> &'static str {
+                match self.raw_symbol() {
+                    Some(symbol) => symbol,
+                    None => self.as_variant_str(),
+                }
+            }
+
+            /// The coin as a JSON object with its `id`, `ids`, `name` (see [Coin::name]), `original_name` (see
+            /// [Coin::coin_name]), `symbol`, duplicate symbols included, and `path_component`, the hardened coin type as
+            /// SLIP-0044 writes it.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(
+            ///     Coin::Credits.to_json(),
+            ///     serde_json::json!({
+            ///         "id": 334,
+            ///         "ids": [334, 498],
+            ///         "name": "Credits",
+            ///         "original_name": "Credits",
+            ///         "symbol": "CS",
+            ///         "path_component": "0x8000014e",
+            ///     }),
+            /// );
+            /// assert_eq!(Coin::OpenAssets.to_json()["symbol"], serde_json::Value::Null);
+            /// # }
+            /// ```
+            #[cfg(feature = "serde")]
+            pub fn to_json(self) -> serde_json::Value {
+                serde_json::json!({
+                    "id": self.id(),
+                    "ids": self.ids(),
+                    "name": self.name(),
+                    "original_name": self.coin_name(),
+                    "symbol": self.raw_symbol(),
+                    "path_component": format!("{:#010x}", self.hardened_coin_type()),
+                })
+            }
+
+            /// One line of a coin listing: coin type right-aligned to `id_width`, symbol left-aligned to `sym_width`
+            /// and the original coin name, separated by two spaces. Coins without a symbol get a blank symbol column.
+            ///
+            /// Use [Coin::MAX_ID_DIGITS] as `id_width` to fit every coin type.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Ethereum.table_row(4, 5), "  60  ETH    Ether");
+            /// assert_eq!(Coin::Bitcoin.table_row(Coin::MAX_ID_DIGITS, 5), "         0  BTC    Bitcoin");
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn table_row(self, id_width: usize, sym_width: usize) -> String {
+                format!(
+                    "{:>id_width$}  {:<sym_width$}  {}",
+                    self.id(),
+                    self.raw_symbol().unwrap_or(""),
+                    self.coin_name(),
+                )
+            }
+
+            /// Whether `s` is one of the coin IDs or, compared case-insensitively, the coin symbol.
+            ///
+            /// Symbols are matched whether they are linked to [Symbol] or only recorded as [Coin::duplicate_symbol].
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert!(Coin::Bitcoin.matches_id_or_symbol("0"));
+            /// assert!(Coin::Bitcoin.matches_id_or_symbol("btc"));
+            /// assert!(Coin::CPChain.matches_id_or_symbol("CPC"));
+            /// assert!(!Coin::Bitcoin.matches_id_or_symbol("60"));
+            /// assert!(!Coin::Bitcoin.matches_id_or_symbol("ETH"));
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn matches_id_or_symbol(self, s: &str) -> bool {
+                match s.parse::<u32>() {
+                    Ok(id) => self.ids().contains(&id),
+                    Err(_) => self.raw_symbol().is_some_and(|ticker| ticker.eq_ignore_ascii_case(s)),
+                }
+            }
+
+            /// Distinct coins matching any of `queries` as of [Coin::matches_id_or_symbol], in coin type order.
+            /// Queries matching no coin are ignored.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::resolve_many(&["eth", "0", "60", "BTC", "nope"]), vec![Coin::Bitcoin, Coin::Ethereum]);
+            /// assert_eq!(Coin::resolve_many(&["CPC"]), vec![Coin::Capricoin, Coin::CPChain]);
+            /// # }
+            /// ```
+            #[cfg(feature = "alloc")]
+            pub fn resolve_many(queries: &[&str]) -> Vec<Self> {
+                Self::all().filter(|coin| queries.iter().any(|query| coin.matches_id_or_symbol(query))).collect()
+            }
+
+            /// Coin symbol as listed in SLIP-0044, regardless of whether it's linked to [Symbol].
+            ///
+            /// A symbol shared by several coins is owned by the one with the lowest coin type: only that coin's
+            /// [Coin::symbol] returns it, the others return `None` there and carry it as a [Coin::duplicate_symbol].
+            /// All of them return it here.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(Coin::Capricoin.raw_symbol(), Some("CPC"));
+            /// assert_eq!(Coin::CPChain.raw_symbol(), Some("CPC"));
+            /// assert_eq!(Coin::CPChain.symbol(), None);
+            /// assert_eq!(Coin::OpenAssets.raw_symbol(), None);
+            /// # }
+            /// ```
+            pub const fn raw_symbol(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$ident => $crate::coins!(@ticker $($symbol)? $(, $duplicate_symbol)?), )*
+                }
+            }
+        }
+
+        impl PartialOrd for Coin {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Coin {
+            /// Orders coins by their primary coin type (see [Coin::id]), and coins sharing one, i.e. testnets, in
+            /// registry order.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// let mut coins = vec![Coin::Ethereum, Coin::Credits, Coin::Bitcoin];
+            /// coins.sort();
+            /// assert_eq!(coins, vec![Coin::Bitcoin, Coin::Ethereum, Coin::Credits]);
+            /// # }
+            /// ```
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                (self.id(), self.registry_index()).cmp(&(other.id(), other.registry_index()))
+            }
+        }
+
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for Coin {
+            /// Picks one of [Coin::all] uniformly.
+            /// ```
+            /// use arbitrary::{Arbitrary, Unstructured};
+            /// use nil_slip44::Coin;
+            ///
+            /// let mut u = Unstructured::new(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]);
+            /// let coin = Coin::arbitrary(&mut u).unwrap();
+            /// assert!(Coin::all().any(|c| c == coin));
+            /// ```
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                u.choose(Self::ALL).copied()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for Coin {
+            /// Serializes the coin as its primary coin type, see [Coin::id] and
+            /// [serde_symbol](crate::serde_symbol) for the symbol instead.
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(serde_json::to_string(&Coin::Ethereum).unwrap(), "60");
+            /// assert_eq!(serde_json::to_string(&Coin::Credits).unwrap(), "334");
+            /// # }
+            /// ```
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.id())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for Coin {
+            /// Deserializes a coin from any of its coin types, see [Coin::from_id].
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(serde_json::from_str::<Coin>("498").unwrap(), Coin::Credits);
+            /// assert!(serde_json::from_str::<Coin>("2147483647").is_err());
+            /// assert!(serde_json::from_str::<Coin>("\"BTC\"").is_err());
+            /// # }
+            /// ```
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct IdVisitor;
+
+                impl serde::de::Visitor<'_> for IdVisitor {
+                    type Value = Coin;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        f.write_str("a SLIP-0044 coin type")
+                    }
+
+                    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                        u32::try_from(v)
+                            .ok()
+                            .and_then(Coin::from_id)
+                            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+                    }
+
+                    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                        u64::try_from(v)
+                            .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+                            .and_then(|v| self.visit_u64(v))
+                    }
+                }
+
+                deserializer.deserialize_u32(IdVisitor)
+            }
+        }
+
+        impl core::convert::TryFrom<u32> for Coin {
+            type Error = $crate::UnknownCoinId;
+
+            /// Coin with any of its IDs, see [Coin::from_id].
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use std::convert::{TryFrom, TryInto};
+            /// use nil_slip44::{Coin, UnknownCoinId};
+            ///
+            /// assert_eq!(Coin::try_from(0), Ok(Coin::Bitcoin));
+            /// assert_eq!(Coin::try_from(498), Ok(Coin::Credits));
+            /// assert_eq!(Coin::try_from(2147483647), Err(UnknownCoinId(2147483647)));
+            ///
+            /// let coin: Coin = 60u32.try_into().unwrap();
+            /// assert_eq!(coin, Coin::Ethereum);
+            /// # }
+            /// ```
+            fn try_from(id: u32) -> Result<Self, Self::Error> {
+                Self::from_id(id).ok_or($crate::UnknownCoinId(id))
+            }
+        }
+
+        impl From<Coin> for u32 {
+            /// Primary coin type of the coin, which is also its lowest, see [Coin::id].
+            /// ```
+            /// # #[cfg(feature = "full")] {
+            /// use nil_slip44::Coin;
+            ///
+            /// assert_eq!(u32::from(Coin::Bitcoin), 0);
+            /// assert_eq!(u32::from(Coin::Credits), 334);
+            /// # }
+            /// ```
+            fn from(coin: Coin) -> Self {
+                coin.id()
+            }
+        }
+
+        impl From<Coin> for &'static str {
+            /// Coin symbol, or for a coin without one, the name of its [Coin] variant, i.e.
+            /// [Coin::symbol_or_variant]. Never allocates.
+            ///
+            /// A symbol-less coin therefore yields its Rust identifier, e.g. `OpenAssets`, not a ticker.
             /// ```
+            /// # #[cfg(feature = "full")] {
             /// use nil_slip44::Coin;
             ///
-            /// assert_eq!(Coin::UniformFiscalObject.name(), "Uniform Fiscal Object");
+            /// let symbol: &str = Coin::Bitcoin.into();
+            /// assert_eq!(symbol, "BTC");
+            /// assert_eq!(<&str>::from(Coin::OpenAssets), "OpenAssets");
+            /// # }
             /// ```
-            pub fn name(self) -> String { match self { $(Self::$ident => $name.to_string(), )* } }
+            fn from(coin: Coin) -> Self {
+                coin.symbol_or_variant()
+            }
+        }
 
-            /// Coin symbol that's not included into [Symbol] enum due to being a duplicate of another coin by symbol name.
+        impl AsRef<str> for Coin {
+            /// Name of the coin's variant, [Coin::name].
             /// ```
+            /// # #[cfg(any(feature = "full", feature = "core-coins"))] {
             /// use nil_slip44::Coin;
             ///
-            /// assert_eq!(Coin::CPChain.duplicate_symbol(), Some("CPC".to_string()));
+            /// assert_eq!(Coin::Bitcoin.as_ref(), "Bitcoin");
+            /// assert_eq!(Coin::Ethereum.as_ref(), "Ethereum");
+            /// # }
             /// ```
-            /// Such conflicts are resolved by taking only first coin (ordered by id) into [Symbol] enum
-            /// (e.g. both [Coin::CPChain] and [Coin::Capricoin] has symbol "CPC" but only [Coin::Capricoin] is eligible to be linked to [Symbol::CPC] since
+            fn as_ref(&self) -> &str {
+                self.name()
+            }
+        }
+
+        impl PartialEq<u32> for Coin {
+            /// Whether `other` is any of the coin's [Coin::ids], not just the primary one, so a coin with several IDs
+            /// equals each of them, unlike `u32::from(coin)` which is only the primary ID.
             /// ```
+            /// # #[cfg(feature = "full")] {
             /// use nil_slip44::Coin;
             ///
-            /// assert!(Coin::Capricoin.id() < Coin::CPChain.id());
+            /// assert!(Coin::Ethereum == 60);
+            /// assert!(Coin::Credits == 334 && Coin::Credits == 498);
+            /// assert!(Coin::Bitcoin != 60);
+            /// # }
             /// ```
-            pub fn duplicate_symbol(self) -> Option<String> {
-                match self {
-                    $($(Self::$ident => Some($duplicate_symbol.to_string()), )?)*
-                    _ => None
-                }
+            fn eq(&self, other: &u32) -> bool {
+                self.ids().contains(other)
             }
         }
 
-        impl std::convert::TryFrom<u32> for Coin {
-            type Error = &'static str;
-
+        impl PartialEq<Coin> for u32 {
+            /// Whether `self` is any of the coin's [Coin::ids], as `coin == id` checks.
             /// ```
-            /// use std::convert::TryFrom;
+            /// # #[cfg(feature = "full")] {
             /// use nil_slip44::Coin;
             ///
-            /// assert_eq!(Coin::try_from(0), Ok(Coin::Bitcoin));
-            /// assert!(Coin::try_from(2147483647).is_err());
+            /// assert!(498 == Coin::Credits);
+            /// # }
             /// ```
-            fn try_from(id: u32) -> Result<Self, Self::Error> {
-                match id {
-                    $($id => Ok(Self::$ident), )*
-                    _ => slip44_error!("unknown coin type")
-                }
+            fn eq(&self, other: &Coin) -> bool {
+                other == self
             }
         }
 
@@ -117,37 +1902,62 @@ macro_rules! coins {
             }
         }
 
-        impl std::str::FromStr for Coin {
-            type Err = &'static str;
+        #[cfg(feature = "alloc")]
+        impl core::str::FromStr for Coin {
+            type Err = $crate::ParseCoinError;
 
+            /// Coin by its exact symbol, duplicate symbols (see [Coin::duplicate_symbol]) included, or else by the
+            /// name of its variant.
+            ///
+            /// Fails with [ParseCoinError::Ambiguous](crate::ParseCoinError::Ambiguous) if more than one coin has
+            /// the symbol, see [Coin::coin_name] and [Coin::from_original_name] for parsing unedited names.
             /// ```
+            /// # #[cfg(feature = "full")] {
             /// use std::str::FromStr;
-            /// use nil_slip44::Coin;
+            /// use nil_slip44::{Coin, ParseCoinError};
             ///
-            /// assert_eq!(Coin::from_str("Uniform Fiscal Object"), Ok(Coin::UniformFiscalObject));
-            /// assert!(Coin::from_str("Unknown Coin That's Definitely Not Exist").is_err());
+            /// assert_eq!(Coin::from_str("ETH"), Ok(Coin::Ethereum));
+            /// assert_eq!(Coin::from_str("Ethereum"), Ok(Coin::Ethereum));
+            /// assert_eq!(Coin::from_str("UniformFiscalObject"), Ok(Coin::UniformFiscalObject));
+            /// assert_eq!(
+            ///     Coin::from_str("CPC"),
+            ///     Err(ParseCoinError::Ambiguous { symbol: "CPC".to_string(), ids: vec![289, 337] }),
+            /// );
+            /// assert_eq!(Coin::from_str("NOPE"), Err(ParseCoinError::UnknownSymbol("NOPE".to_string())));
+            /// assert_eq!(Coin::from_str("Not A Coin"), Err(ParseCoinError::UnknownName("Not A Coin".to_string())));
+            /// # }
             /// ```
-            #[allow(unreachable_patterns)]
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    $($name => Ok(Self::$ident), )*
-                    _ => slip44_error!("unknown coin")
+                let mut coins = Self::coins_for_symbol(s);
+                match (coins.next(), coins.next()) {
+                    (Some(coin), None) => Ok(coin),
+                    (Some(first), Some(second)) => Err($crate::ParseCoinError::Ambiguous {
+                        symbol: s.to_string(),
+                        ids: [first, second].into_iter().chain(coins).map(Self::id).collect(),
+                    }),
+                    (None, _) => Self::from_variant_str(s).ok_or_else(|| {
+                        if $crate::SymbolQuality::of(s).passes() {
+                            $crate::ParseCoinError::UnknownSymbol(s.to_string())
+                        } else {
+                            $crate::ParseCoinError::UnknownName(s.to_string())
+                        }
+                    }),
                 }
             }
         }
 
         #[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
-        #[allow(non_camel_case_types)]
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
         /// Coin symbol according to [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) spec.
         pub enum Symbol { $($($symbol, )?)* }
 
-        impl std::fmt::Display for Symbol {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Display for Symbol {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(f, "{}", match self { $($(Self::$symbol => stringify!($symbol), )?)* })
             }
         }
 
-        impl std::convert::TryFrom<u32> for Symbol {
+        impl core::convert::TryFrom<u32> for Symbol {
             type Error = &'static str;
 
             /// ```
@@ -156,11 +1966,14 @@ macro_rules! coins {
             /// assert_eq!(Symbol::BTC.to_string(), "BTC");
             /// ```
             fn try_from(id: u32) -> Result<Self, Self::Error> {
-                Coin::try_from(id).map(Symbol::try_from)?
+                match Coin::from_id(id) {
+                    Some(coin) => Symbol::try_from(coin),
+                    None => slip44_error!("unknown coin type"),
+                }
             }
         }
 
-        impl std::convert::TryFrom<Coin> for Symbol {
+        impl core::convert::TryFrom<Coin> for Symbol {
             type Error = &'static str;
 
             /// ```
@@ -178,7 +1991,7 @@ macro_rules! coins {
             }
         }
 
-        impl std::str::FromStr for Symbol {
+        impl core::str::FromStr for Symbol {
             type Err = &'static str;
 
             /// ```
@@ -196,17 +2009,30 @@ macro_rules! coins {
             }
         }
     };
+    (@symbol $symbol:ident) => { Some(stringify!($symbol)) };
+    (@symbol) => { None };
+    (@ticker $symbol:ident) => { Some(stringify!($symbol)) };
+    (@ticker , $duplicate_symbol:expr) => { Some($duplicate_symbol) };
+    (@ticker) => { None };
 }
 
 #[cfg(test)]
 mod tests {
-    use std::convert::TryFrom;
-    use std::str::FromStr;
+    use core::convert::TryFrom;
+    #[cfg(feature = "alloc")]
+    use core::str::FromStr;
+
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use crate::prelude::*;
+
+    use crate::SymbolQuality;
+    #[cfg(feature = "alloc")]
+    use crate::{CoinError, CoinFlags, HardenedStyle, ParseCoinError};
 
     coins!(
         (
             /// OG Crypto
-            [0, 500], Bitcoin, "Bitcoin by Satoshi", BTC,
+            [0, 500], Bitcoin, "Bitcoin by Satoshi", BTC, , "https://bitcoin.org",
         ),
         (
             /// Any Crypto's Testnet
@@ -214,25 +2040,878 @@ mod tests {
         ),
     );
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn coin() {
-        assert_eq!(Coin::Bitcoin.to_string(), "Bitcoin");
+        assert_eq!(Coin::Bitcoin.to_string(), "Bitcoin by Satoshi");
+        assert_eq!(format!("{:?}", Coin::Bitcoin), "Bitcoin");
         assert_eq!(Coin::Bitcoin.id(), 0);
-        assert_eq!(Coin::Bitcoin.ids(), vec![0, 500]);
-        assert_eq!(Coin::Bitcoin.name(), "Bitcoin by Satoshi");
+        assert_eq!(Coin::Bitcoin.ids(), [0, 500]);
+        assert_eq!(Coin::Testnet.ids(), [1]);
+        assert_eq!(Coin::Bitcoin.name(), "Bitcoin");
+        assert_eq!(Coin::Bitcoin.coin_name(), "Bitcoin by Satoshi");
+        assert_eq!(Coin::Bitcoin.symbol(), Some("BTC"));
+        assert_eq!(Coin::Testnet.symbol(), None);
         assert_eq!(Coin::Bitcoin.duplicate_symbol(), None);
+        assert_eq!(Coin::Bitcoin.homepage(), Some("https://bitcoin.org"));
+        assert_eq!(Coin::Testnet.homepage(), None);
+        assert_eq!(Coin::Bitcoin.as_variant_str(), "Bitcoin");
+        assert_eq!(Coin::from_variant_str("Testnet"), Some(Coin::Testnet));
+        assert_eq!(Coin::from_variant_str("Bitcoin by Satoshi"), None);
+
+        assert_eq!(
+            Coin::all().collect::<Vec<_>>(),
+            vec![Coin::Bitcoin, Coin::Testnet]
+        );
+        assert_eq!(Coin::all_by_name(), vec![Coin::Bitcoin, Coin::Testnet]);
+        assert_eq!(Coin::nth_or_last(1), Coin::Testnet);
+        assert_eq!(Coin::nth_or_last(2), Coin::Testnet);
+        assert_eq!(
+            Coin::all_rev().collect::<Vec<_>>(),
+            vec![Coin::Testnet, Coin::Bitcoin]
+        );
+
+        assert_eq!(Coin::from_legacy_name("OldBitcoin"), Some(Coin::Bitcoin));
+        assert_eq!(Coin::from_legacy_name("Bitcoin"), None);
 
         assert_eq!(Coin::try_from(0), Ok(Coin::Bitcoin));
         assert!(Coin::try_from(100).is_err());
 
+        assert_eq!(
+            Coin::Bitcoin.flags(),
+            CoinFlags::HAS_SYMBOL | CoinFlags::IS_MULTI_ID
+        );
+        assert_eq!(
+            Coin::Testnet.flags(),
+            CoinFlags::HAS_SYMBOL | CoinFlags::IS_TESTNET
+        );
+
+        assert_eq!(Coin::Bitcoin.coin_type_nonzero(), None);
+        assert_eq!(
+            Coin::Testnet.coin_type_nonzero(),
+            core::num::NonZeroU32::new(1)
+        );
+
+        assert_eq!(Coin::Testnet.coin_type_be_bytes(), [0, 0, 0, 1]);
+        assert_eq!(Coin::from_be_bytes([0, 0, 0, 1]), Some(Coin::Testnet));
+        assert_eq!(Coin::from_be_bytes([1, 0, 0, 0]), None);
+
+        assert_eq!(Coin::Bitcoin.supports_segwit(), Some(true));
+        assert_eq!(Coin::Testnet.supports_segwit(), None);
+
+        assert_eq!(Coin::from_id_or(0, Coin::Testnet), Coin::Bitcoin);
+        assert_eq!(Coin::from_id_or(100, Coin::Testnet), Coin::Testnet);
+
         assert_eq!(Coin::from(Symbol::BTC), Coin::Bitcoin);
 
         assert_eq!(Coin::Testnet.duplicate_symbol(), Some("TSNT".to_string()));
 
-        assert_eq!(Coin::from_str("Bitcoin by Satoshi"), Ok(Coin::Bitcoin));
-        assert!(Coin::from_str("Somecoin").is_err());
+        assert_eq!(Coin::Bitcoin.symbol_len(), Some(3));
+        assert_eq!(Coin::Testnet.symbol_len(), Some(4));
+
+        assert_eq!(Coin::Bitcoin.symbol_or_variant(), "BTC");
+        assert_eq!(Coin::Testnet.symbol_or_variant(), "TSNT");
+        assert_eq!(Coin::Testnet.raw_symbol(), Some("TSNT"));
+        assert_eq!(Coin::Bitcoin.raw_symbol(), Some("BTC"));
+
+        assert_eq!(
+            Coin::symbol_suggestions("BTX", 2),
+            vec![Coin::Bitcoin, Coin::Testnet]
+        );
+        assert_eq!(Coin::symbol_suggestions("TSN", 1), vec![Coin::Testnet]);
+
+        let scores = Coin::fuzzy_with_scores("tsnt");
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0], (Coin::Testnet, 1.0));
+        assert!(scores[1].1 < 1.0);
+        assert_eq!(Coin::fuzzy_with_scores("q").len(), 0);
+
+        #[cfg(feature = "std")]
+        {
+            let coins = Coin::resolve_symbols(&["btc", "tsnt", "ETH"]);
+            assert_eq!(coins.len(), 3);
+            assert_eq!(coins["btc"], Some(Coin::Bitcoin));
+            assert_eq!(coins["tsnt"], Some(Coin::Testnet));
+            assert_eq!(coins["ETH"], None);
+        }
+
+        assert_eq!(
+            Coin::coins_with_symbol_in("swap btc for TSNT"),
+            vec![Coin::Bitcoin, Coin::Testnet]
+        );
+        assert!(Coin::coins_with_symbol_in("BTCTSNT").is_empty());
+
+        assert!(Coin::Bitcoin.matches_id_or_symbol("500"));
+        assert!(Coin::Bitcoin.matches_id_or_symbol("Btc"));
+        assert!(Coin::Testnet.matches_id_or_symbol("tsnt"));
+        assert!(!Coin::Testnet.matches_id_or_symbol("BTC"));
+        assert!(!Coin::Testnet.matches_id_or_symbol("0"));
+        assert_eq!(
+            Coin::resolve_many(&["tsnt", "500", "1", "btc", "0", "2"]),
+            vec![Coin::Bitcoin, Coin::Testnet]
+        );
+        assert!(Coin::resolve_many(&[]).is_empty());
+
+        assert_eq!(Coin::from_str("BTC"), Ok(Coin::Bitcoin));
+        assert_eq!(Coin::from_str("TSNT"), Ok(Coin::Testnet));
+        assert_eq!(Coin::from_str("Testnet"), Ok(Coin::Testnet));
+        assert_eq!(
+            Coin::from_str("Bitcoin by Satoshi"),
+            Err(ParseCoinError::UnknownName(
+                "Bitcoin by Satoshi".to_string()
+            ))
+        );
+        assert_eq!(
+            Coin::from_str("btc"),
+            Err(ParseCoinError::UnknownName("btc".to_string()))
+        );
+        assert_eq!(
+            Coin::from_str("SOME"),
+            Err(ParseCoinError::UnknownSymbol("SOME".to_string()))
+        );
+    }
+
+    #[test]
+    fn from_symbol() {
+        assert_eq!(Coin::from_symbol("BTC"), Some(Coin::Bitcoin));
+        assert_eq!(Coin::from_symbol("Btc"), None);
+        assert_eq!(Coin::from_symbol("TSNT"), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_symbol_ci() {
+        for symbol in ["btc", "Btc", "BTC"] {
+            assert_eq!(Coin::from_symbol_ci(symbol), Some(Coin::Bitcoin));
+        }
+        assert_eq!(Coin::from_symbol_ci("tsnt"), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_symbol_ci_case_collision() {
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([2], KUSD, "KUSD", KUSD,),
+                ([3], kUSD, "kUSD", kUSD,),
+                ([4], Kusd, "Kusd", Kusd,),
+            );
+        }
+
+        assert_eq!(
+            registry::Coin::from_symbol_ci("kUSD"),
+            Some(registry::Coin::kUSD)
+        );
+        assert_eq!(
+            registry::Coin::from_symbol_ci("kusd"),
+            Some(registry::Coin::KUSD)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn from_str_ambiguous_symbol() {
+        #[allow(dead_code)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([2], BitcoinFork, "Bitcoin Fork",, "BTC"),
+                ([3], Dogecoin, "Dogecoin", DOGE,),
+            );
+        }
+
+        assert_eq!(
+            registry::Coin::from_str("BTC"),
+            Err(ParseCoinError::Ambiguous {
+                symbol: "BTC".to_string(),
+                ids: vec![0, 2],
+            })
+        );
+        assert_eq!(
+            registry::Coin::from_str("DOGE"),
+            Ok(registry::Coin::Dogecoin)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn check_external_mapping() {
+        assert_eq!(Coin::check_external_mapping(0, "btc"), Ok(()));
+        assert_eq!(Coin::check_external_mapping(2, "LTC"), Ok(()));
+        assert_eq!(
+            Coin::check_external_mapping(1, "TBTC"),
+            Err(CoinError::SymbolMismatch {
+                id: 1,
+                expected: Some("TSNT"),
+                found: "TBTC".to_string()
+            })
+        );
+        assert_eq!(
+            CoinError::SymbolMismatch {
+                id: 1,
+                expected: None,
+                found: "TBTC".to_string()
+            }
+            .to_string(),
+            "coin type 1 has no symbol, not TBTC"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn enumerate() {
+        assert_eq!(
+            Coin::enumerate().collect::<Vec<_>>(),
+            vec![(0, Coin::Bitcoin), (1, Coin::Testnet)]
+        );
+        for (index, coin) in Coin::enumerate() {
+            assert_eq!(index, coin.registry_index());
+        }
+    }
+
+    #[test]
+    fn id_index() {
+        assert_eq!(
+            Coin::ID_INDEX,
+            &[(0, Coin::Bitcoin), (1, Coin::Testnet), (500, Coin::Bitcoin)]
+        );
+        assert_eq!(Coin::from_id(500), Some(Coin::Bitcoin));
+        assert_eq!(Coin::from_id(1), Some(Coin::Testnet));
+        assert_eq!(Coin::from_id(2), None);
+    }
+
+    #[test]
+    fn stable_hash() {
+        assert_eq!(Coin::Bitcoin.stable_hash(), Coin::Bitcoin.stable_hash());
+        assert_ne!(Coin::Bitcoin.stable_hash(), Coin::Testnet.stable_hash());
+    }
+
+    #[test]
+    fn from_id_shared_id() {
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([2, 9], Alpha, "Alpha", ALPHA,),
+                ([3, 9], Beta, "Beta", BETA,),
+                ([9], Gamma, "Gamma", GAMMA,),
+            );
+        }
+
+        assert_eq!(registry::Coin::from_id(9), Some(registry::Coin::Alpha));
+        assert_eq!(registry::Coin::from_id(3), Some(registry::Coin::Beta));
+    }
+
+    #[test]
+    fn resolve_numeric() {
+        assert_eq!(Coin::resolve_numeric(0), Some(Coin::Bitcoin));
+        assert_eq!(Coin::resolve_numeric(0x8000_0001), Some(Coin::Testnet));
+        assert_eq!(Coin::resolve_numeric(0x1_8000_0001), None);
+        assert_eq!(Coin::resolve_numeric(2), None);
+    }
+
+    #[test]
+    fn mainnet_coin_type() {
+        assert_eq!(Coin::Bitcoin.mainnet_coin_type(), Some(0));
+        assert_eq!(Coin::Testnet.mainnet_coin_type(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn self_check() {
+        assert_eq!(Coin::self_check(), Ok(()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn self_check_duplicate_primary_id() {
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([0], BitcoinFork, "Bitcoin Fork", BTF,),
+            );
+        }
+
+        assert_eq!(
+            registry::Coin::self_check(),
+            Err(CoinError::InconsistentRegistry(
+                "coin type 0 is the primary ID of more than one coin".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn search() {
+        assert_eq!(Coin::search("bit"), vec![Coin::Bitcoin]);
+        assert_eq!(Coin::search("SATOSHI"), vec![Coin::Bitcoin]);
+        assert_eq!(Coin::search("t"), vec![Coin::Testnet, Coin::Bitcoin]);
+        assert_eq!(Coin::search(""), vec![Coin::Bitcoin, Coin::Testnet]);
+        assert!(Coin::search("eth").is_empty());
+    }
+
+    #[test]
+    fn from_coin_name() {
+        assert_eq!(
+            Coin::from_coin_name("Bitcoin by Satoshi"),
+            Some(Coin::Bitcoin)
+        );
+        assert_eq!(
+            Coin::from_coin_name("Testnet (all coins)"),
+            Some(Coin::Testnet)
+        );
+        assert_eq!(Coin::from_coin_name("Testnet"), None);
+        assert_eq!(Coin::from_coin_name("bitcoin by satoshi"), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn from_original_name() {
+        assert_eq!(
+            Coin::all_from_original_name("Bitcoin by Satoshi"),
+            vec![Coin::Bitcoin]
+        );
+        assert_eq!(Coin::from_original_name("Testnet"), None);
+
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([2], Nexus_NXS, "Nexus", NXS,),
+                ([3], Nexus_NEXUS, "Nexus", NEXUS,),
+            );
+        }
+        assert_eq!(
+            registry::Coin::all_from_original_name("Nexus"),
+            vec![registry::Coin::Nexus_NXS, registry::Coin::Nexus_NEXUS]
+        );
+        assert_eq!(registry::Coin::from_original_name("Nexus"), None);
+        assert_eq!(
+            registry::Coin::from_original_name("Bitcoin"),
+            Some(registry::Coin::Bitcoin)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn shared_testnet_coin_type() {
+        #[allow(dead_code)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([1], Testnet, "Testnet (all coins)",,),
+                ([1], SignetTestnet, "Signet Testnet",,),
+            );
+        }
+        use registry::Coin;
+
+        assert_eq!(Coin::self_check(), Ok(()));
+        assert_eq!(Coin::try_from(1), Ok(Coin::Testnet));
+        assert!(Coin::Testnet.flags().is_testnet());
+        assert!(Coin::SignetTestnet.flags().is_testnet());
+        assert_eq!(Coin::SignetTestnet.mainnet_coin_type(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn try_from_u32() {
+        use crate::UnknownCoinId;
+
+        assert_eq!(Coin::try_from(1), Ok(Coin::Testnet));
+        assert_eq!(Coin::try_from(500), Ok(Coin::Bitcoin));
+        assert_eq!(Coin::try_from(u32::MAX), Err(UnknownCoinId(u32::MAX)));
+        assert_eq!(
+            UnknownCoinId(u32::MAX).to_string(),
+            "unknown coin type 4294967295"
+        );
+        assert_eq!(u32::from(Coin::Bitcoin), 0);
+        assert_eq!(u32::from(Coin::Testnet), 1);
+    }
+
+    #[test]
+    fn as_ref() {
+        assert_eq!(Coin::Bitcoin.as_ref(), "Bitcoin");
+        assert_eq!(Coin::Testnet.as_ref(), "Testnet");
+    }
+
+    #[test]
+    fn eq_id() {
+        assert!(Coin::Bitcoin == 0);
+        assert!(Coin::Bitcoin == 500);
+        assert!(500 == Coin::Bitcoin);
+        assert!(Coin::Bitcoin != 1);
+        assert!(1 != Coin::Bitcoin);
+        assert!(Coin::Testnet == 1);
+    }
+
+    #[test]
+    fn str_from_coin() {
+        assert_eq!(<&str>::from(Coin::Bitcoin), "BTC");
+        assert_eq!(<&str>::from(Coin::Testnet), "TSNT");
+
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([21], OpenAssets, "Open Assets",,),
+            );
+        }
+        let symbol: &str = registry::Coin::OpenAssets.into();
+        assert_eq!(symbol, "OpenAssets");
+    }
+
+    #[test]
+    fn canonical_id() {
+        assert_eq!(Coin::Bitcoin.canonical_id(), 0);
+        assert_eq!(Coin::Testnet.canonical_id(), 1);
+
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([9, 4, 7], Unsorted, "Unsorted",,),
+            );
+        }
+        assert_eq!(registry::Coin::Unsorted.id(), 9);
+        assert_eq!(registry::Coin::Unsorted.canonical_id(), 4);
+    }
+
+    #[test]
+    fn discriminant() {
+        assert_eq!(Coin::Bitcoin.discriminant(), 0);
+        for coin in Coin::all() {
+            assert_eq!(Coin::from_id(coin.discriminant()), Some(coin));
+        }
+    }
+
+    #[test]
+    fn from_index() {
+        for coin in Coin::all() {
+            assert_eq!(Coin::from_index(coin.index()), Some(coin));
+        }
+        assert_eq!(Coin::from_index(Coin::ALL.len()), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn name_truncated() {
+        assert_eq!(Coin::Bitcoin.name_truncated(10), "Bitcoin b…");
+        assert_eq!(Coin::Bitcoin.name_truncated(18), "Bitcoin by Satoshi");
+        assert_eq!(Coin::Bitcoin.name_truncated(17).chars().count(), 17);
+        assert_eq!(Coin::Testnet.name_truncated(100), "Testnet (all coins)");
+        assert_eq!(Coin::Testnet.name_truncated(0), "");
+
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(([0], Ethereum, "Ēther Ćlassic", ETC,),);
+        }
+        assert_eq!(registry::Coin::Ethereum.name_truncated(3), "Ēt…");
+    }
+
+    #[test]
+    fn is_evm() {
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([60], Ethereum, "Ether", ETH,),
+            );
+        }
+
+        assert!(registry::Coin::Ethereum.is_evm());
+        assert!(!registry::Coin::Bitcoin.is_evm());
+        assert!(!Coin::Testnet.is_evm());
+    }
+
+    #[test]
+    fn base_symbol() {
+        assert_eq!(Coin::Bitcoin.base_symbol(), "BTC");
+        assert_eq!(Coin::Testnet.base_symbol(), "TSNT");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ord() {
+        #[allow(dead_code, unreachable_patterns)]
+        mod registry {
+            coins!(
+                ([60], Ethereum, "Ether", ETH,),
+                ([1], Testnet, "Testnet (all coins)",,),
+                ([0, 70], Bitcoin, "Bitcoin", BTC,),
+                ([1], SignetTestnet, "Signet Testnet",,),
+            );
+        }
+        use registry::Coin;
+
+        let mut coins: Vec<_> = Coin::all().collect();
+        coins.sort();
+        assert_eq!(
+            coins,
+            vec![
+                Coin::Bitcoin,
+                Coin::Testnet,
+                Coin::SignetTestnet,
+                Coin::Ethereum
+            ]
+        );
+        let set: alloc::collections::BTreeSet<_> = Coin::all().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), coins);
+    }
+
+    #[test]
+    fn is_testnet() {
+        assert!(Coin::Testnet.is_testnet());
+        assert!(!Coin::Bitcoin.is_testnet());
+    }
+
+    #[test]
+    fn coin_type_offset_from() {
+        assert_eq!(Coin::Testnet.coin_type_offset_from(0), 1);
+        assert_eq!(Coin::Bitcoin.coin_type_offset_from(1), -1);
+        assert_eq!(
+            Coin::Bitcoin.coin_type_offset_from(u32::MAX),
+            -i64::from(u32::MAX)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn coins_for_symbol() {
+        #[allow(dead_code)]
+        mod registry {
+            coins!(
+                ([0], Bitcoin, "Bitcoin", BTC,),
+                ([2], Cash, "Bitcoin Cash", , "BTC",),
+                ([3], Gold, "Bitcoin Gold", , "BTC",),
+            );
+        }
+        use registry::Coin;
+
+        let coins: Vec<_> = Coin::coins_for_symbol("BTC").collect();
+        assert_eq!(coins, vec![Coin::Bitcoin, Coin::Cash, Coin::Gold]);
+        assert_eq!(Coin::coins_for_symbol("XBT").next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip44_path() {
+        assert_eq!(Coin::Bitcoin.bip44_path(0, 0, 0), "m/44'/0'/0'/0/0");
+        assert_eq!(Coin::Testnet.bip44_path(3, 1, 42), "m/44'/1'/3'/1/42");
+        assert_eq!(Coin::Testnet.hardened_coin_type(), 0x8000_0001);
+        assert_eq!(Coin::Bitcoin.hardened_path_component(), 0x8000_0000);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn derivation_path() {
+        assert_eq!(
+            Coin::Bitcoin.derivation_path(84, 0, 0, 0),
+            "m/84'/0'/0'/0/0"
+        );
+        assert_eq!(
+            Coin::Bitcoin.derivation_path(49, 2, 1, 3),
+            "m/49'/0'/2'/1/3"
+        );
+        assert_eq!(
+            Coin::Testnet.derivation_path(44, 3, 1, 42),
+            Coin::Testnet.bip44_path(3, 1, 42)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip44_path_styled() {
+        assert_eq!(
+            Coin::Testnet.bip44_path_styled(7, HardenedStyle::Apostrophe),
+            "m/44'/1'/7'"
+        );
+        assert_eq!(
+            Coin::Testnet.bip44_path_styled(7, HardenedStyle::LowerH),
+            "m/44h/1h/7h"
+        );
+        assert_eq!(
+            Coin::Testnet.bip44_path_styled(7, HardenedStyle::UpperH),
+            "m/44H/1H/7H"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn account_path_checked() {
+        assert_eq!(
+            Coin::Bitcoin.account_path_checked(0x7fff_ffff),
+            Ok(vec![0x8000_002c, 0x8000_0000, 0xffff_ffff])
+        );
+        assert_eq!(
+            Coin::Testnet.account_path_checked(0x8000_0000),
+            Err(CoinError::HardenedAccount(0x8000_0000))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn account_paths() {
+        assert_eq!(
+            Coin::Bitcoin.account_paths(0..3),
+            vec![
+                vec![0x8000_002c, 0x8000_0000, 0x8000_0000],
+                vec![0x8000_002c, 0x8000_0000, 0x8000_0001],
+                vec![0x8000_002c, 0x8000_0000, 0x8000_0002],
+            ]
+        );
+        assert!(Coin::Testnet
+            .account_paths(0x8000_0000..0x8000_0002)
+            .is_empty());
+        assert!(Coin::Testnet.account_paths(3..3).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip44_components() {
+        assert_eq!(
+            Coin::Testnet.bip44_components(2, 1, 9).collect::<Vec<_>>(),
+            vec![0x8000_002c, 0x8000_0001, 0x8000_0002, 1, 9]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip44_path_public() {
+        assert_eq!(Coin::Testnet.bip44_path_public(7), "M/44'/1'/7'");
+    }
+
+    #[test]
+    fn from_path_components() {
+        assert_eq!(
+            Coin::from_path_components(&[0x8000_002c, 0x8000_0000, 0x8000_0000, 0, 0]),
+            Some(Coin::Bitcoin)
+        );
+        assert_eq!(
+            Coin::from_path_components(&[0x8000_002c, 0x8000_0001]),
+            Some(Coin::Testnet)
+        );
+        assert_eq!(Coin::from_path_components(&[]), None);
+        assert_eq!(Coin::from_path_components(&[0x8000_002c, 1]), None);
+        assert_eq!(Coin::from_path_components(&[44, 0x8000_0000]), None);
+        assert_eq!(
+            Coin::from_path_components(&[0x8000_002c, 0x8000_0002]),
+            None
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn path_for_purpose() {
+        for purpose in [44, 49, 84, 86] {
+            assert_eq!(
+                Coin::Bitcoin.path_for_purpose(purpose, 3),
+                Ok(vec![0x8000_0000 | purpose, 0x8000_0000, 0x8000_0003])
+            );
+        }
+        assert_eq!(
+            Coin::Testnet.path_for_purpose(0, 0),
+            Err(CoinError::UnknownPurpose(0))
+        );
+    }
+
+    #[cfg(feature = "alloy")]
+    #[test]
+    fn alloy() {
+        assert_eq!(Coin::Bitcoin.alloy_chain(), None);
+        assert_eq!(Coin::from_alloy_chain(alloy_chains::Chain::mainnet()), None);
+    }
+
+    #[cfg(feature = "bip32")]
+    #[test]
+    fn coins_bip32() {
+        use coins_bip32::path::DerivationPath;
+
+        let path = Coin::Bitcoin.coins_bip32_path(44, 2, 1, 5).unwrap();
+        assert_eq!(path, "m/44'/0'/2'/1/5".parse::<DerivationPath>().unwrap());
+        assert_eq!(Coin::from_coins_bip32_path(&path), Some(Coin::Bitcoin));
+        assert_eq!(
+            Coin::Testnet.coins_bip32_path(49, 0x8000_0000, 0, 0),
+            Err(CoinError::HardenedAccount(0x8000_0000))
+        );
+        assert_eq!(
+            Coin::from_coins_bip32_path(&"m/44'/2'".parse().unwrap()),
+            None
+        );
+    }
+
+    #[cfg(feature = "caip")]
+    #[test]
+    fn caip19_native() {
+        assert_eq!(
+            Coin::Bitcoin.caip19_native().as_deref(),
+            Some("bip122:000000000019d6689c085ae165831e93/slip44:0")
+        );
+        assert_eq!(
+            Coin::Testnet.caip19_native().as_deref(),
+            Some("bip122:000000000933ea01ad0ee984209779ba/slip44:1")
+        );
+    }
+
+    #[cfg(feature = "caip")]
+    #[test]
+    fn caip2_namespace() {
+        assert_eq!(Coin::Bitcoin.caip2_namespace(), Some("bip122"));
+        assert_eq!(Coin::Testnet.caip2_namespace(), Some("bip122"));
+
+        for &(id, chain) in crate::overlay::CAIP2_CHAIN_IDS {
+            let namespace = crate::overlay::lookup(crate::overlay::CAIP2_NAMESPACES, id);
+            assert_eq!(namespace, chain.split(':').next(), "coin type {}", id);
+        }
+    }
+
+    #[cfg(feature = "relations")]
+    #[test]
+    fn underlying() {
+        assert_eq!(Coin::Bitcoin.underlying(), None);
+        assert_eq!(Coin::Testnet.underlying(), None);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn symbol_grapheme_count() {
+        assert_eq!(
+            Coin::Bitcoin.symbol_grapheme_count(),
+            Some("BTC".chars().count())
+        );
+        assert_eq!(
+            Coin::Testnet.symbol_grapheme_count(),
+            Coin::Testnet.symbol_len()
+        );
+    }
+
+    #[cfg(feature = "stablecoins")]
+    #[test]
+    fn stablecoins() {
+        assert!(!Coin::Bitcoin.hosts_major_stablecoins());
+        assert!(!Coin::Testnet.hosts_major_stablecoins());
+    }
+
+    #[cfg(feature = "lazy-maps")]
+    #[test]
+    fn lazy_maps() {
+        for coin in Coin::all() {
+            for &id in coin.ids() {
+                let scanned = Coin::all().find(|other| other.ids().contains(&id));
+                assert_eq!(Coin::id_map().get(&id).copied(), scanned);
+            }
+            if let Some(symbol) = coin.symbol() {
+                let scanned = Coin::all().find(|other| other.symbol() == Some(symbol));
+                assert_eq!(Coin::symbol_map().get(symbol).copied(), scanned);
+            }
+        }
+        assert_eq!(Coin::id_map().len(), 3);
+        assert_eq!(Coin::symbol_map().len(), 1);
+        assert_eq!(Coin::id_map().get(&2), None);
+        assert_eq!(Coin::symbol_map().get("TSNT"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_json() {
+        assert_eq!(
+            Coin::Bitcoin.to_json(),
+            serde_json::json!({
+                "id": 0,
+                "ids": [0, 500],
+                "name": "Bitcoin",
+                "original_name": "Bitcoin by Satoshi",
+                "symbol": "BTC",
+                "path_component": "0x80000000",
+            })
+        );
+        assert_eq!(Coin::Testnet.to_json()["symbol"], "TSNT");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_id() {
+        for coin in Coin::all() {
+            let json = serde_json::to_string(&coin).unwrap();
+            assert_eq!(json, coin.id().to_string());
+            assert_eq!(serde_json::from_str::<Coin>(&json).unwrap(), coin);
+        }
+        assert_eq!(serde_json::from_str::<Coin>("500").unwrap(), Coin::Bitcoin);
+        assert!(serde_json::from_str::<Coin>("2").is_err());
+        assert!(serde_json::from_str::<Coin>("-1").is_err());
+        assert!(serde_json::from_str::<Coin>("4294967296").is_err());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[0, 1, 2, 3, 127, 128, 254, 255]);
+        for _ in 0..16 {
+            let coin = Coin::arbitrary(&mut u).unwrap();
+            assert!(Coin::all().any(|c| c == coin));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn table_row() {
+        assert_eq!(
+            Coin::Bitcoin.table_row(3, 4),
+            "  0  BTC   Bitcoin by Satoshi"
+        );
+        assert_eq!(
+            Coin::Testnet.table_row(3, 4),
+            "  1  TSNT  Testnet (all coins)"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iter_symbols() {
+        assert_eq!(
+            Coin::iter_symbols().collect::<Vec<_>>(),
+            [("BTC", Coin::Bitcoin)]
+        );
+    }
+
+    #[test]
+    fn from_name() {
+        for coin in Coin::all() {
+            assert_eq!(Coin::from_name(coin.name()), Some(coin));
+        }
+        assert_eq!(Coin::from_name("bitcoin"), None);
+        assert_eq!(Coin::from_name("Testnet (all coins)"), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iter_names() {
+        assert_eq!(
+            Coin::iter_names().collect::<Vec<_>>(),
+            [("Bitcoin", Coin::Bitcoin), ("Testnet", Coin::Testnet)]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn all_symbols_sorted() {
+        assert_eq!(Coin::all_symbols_sorted(), vec!["BTC", "TSNT"]);
+    }
+
+    #[test]
+    fn symbol_quality() {
+        assert!(Coin::Bitcoin
+            .symbol_quality()
+            .is_some_and(SymbolQuality::passes));
+        assert!(Coin::Testnet
+            .symbol_quality()
+            .is_some_and(SymbolQuality::passes));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn symbol() {
         assert_eq!(Symbol::BTC.to_string(), "BTC");
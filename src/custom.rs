@@ -0,0 +1,237 @@
+use alloc::collections::BTreeMap;
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Coin, CoinError};
+
+/// Coin registered at runtime with [CoinRegistry::register], e.g. for a network whose coin type isn't in
+/// SLIP-0044 yet.
+#[derive(Debug, PartialEq, Clone, Hash, Eq)]
+pub struct CustomCoin {
+    /// Coin type.
+    pub id: u32,
+    /// Symbol, looked up exactly by [CoinRegistry::lookup_symbol].
+    pub symbol: String,
+    /// Name of the coin.
+    pub name: String,
+}
+
+/// Coin found by a [CoinRegistry] lookup.
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
+pub enum RegisteredCoin<'a> {
+    /// Coin of the generated registry.
+    Static(Coin),
+    /// Coin registered with [CoinRegistry::register].
+    Custom(&'a CustomCoin),
+}
+
+impl<'a> RegisteredCoin<'a> {
+    /// Coin type, the primary [Coin::id] of a static coin.
+    pub fn id(self) -> u32 {
+        match self {
+            Self::Static(coin) => coin.id(),
+            Self::Custom(custom) => custom.id,
+        }
+    }
+
+    /// Symbol, which only a static coin can lack, see [Coin::symbol].
+    pub fn symbol(self) -> Option<&'a str> {
+        match self {
+            Self::Static(coin) => coin.symbol(),
+            Self::Custom(custom) => Some(&custom.symbol),
+        }
+    }
+
+    /// Name, the [Coin::coin_name] of a static coin.
+    pub fn name(self) -> &'a str {
+        match self {
+            Self::Static(coin) => coin.coin_name(),
+            Self::Custom(custom) => &custom.name,
+        }
+    }
+}
+
+/// The generated coins plus coins registered at runtime, for coin types SLIP-0044 doesn't list yet, without
+/// forking the crate.
+///
+/// Registered coins can't take the coin type of a generated one, [CoinRegistry::register] fails instead, so a
+/// lookup by ID only ever finds one coin. Registering a coin type again replaces the registered coin. A
+/// registered symbol shadows a generated coin with the same symbol though, since lookups check the registered
+/// coins first.
+/// ```
+/// use nil_slip44::{Coin, CoinRegistry, RegisteredCoin};
+///
+/// let mut registry = CoinRegistry::new();
+/// registry.register(2_000_000_000, "NEW", "New Network").unwrap();
+///
+/// assert_eq!(registry.lookup_id(2_000_000_000).map(RegisteredCoin::id), Some(2_000_000_000));
+/// assert_eq!(registry.lookup_symbol("BTC"), Some(RegisteredCoin::Static(Coin::Bitcoin)));
+/// assert!(registry.register(0, "BTC2", "Not Bitcoin").is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CoinRegistry {
+    custom: BTreeMap<u32, CustomCoin>,
+}
+
+impl CoinRegistry {
+    /// Registry of the generated coins only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a coin with coin type `id`, returning the coin registered with it before, if any.
+    ///
+    /// Fails with [CoinError::KnownCoinType] if a generated coin has the coin type, primary or secondary.
+    pub fn register(
+        &mut self,
+        id: u32,
+        symbol: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Result<Option<CustomCoin>, CoinError> {
+        if let Some(coin) = Coin::from_id(id) {
+            return Err(CoinError::KnownCoinType { id, coin });
+        }
+        let custom = CustomCoin {
+            id,
+            symbol: symbol.into(),
+            name: name.into(),
+        };
+        Ok(self.custom.insert(id, custom))
+    }
+
+    /// Coin with the coin type `id`, registered or else generated, see [Coin::from_id].
+    pub fn lookup_id(&self, id: u32) -> Option<RegisteredCoin<'_>> {
+        match self.custom.get(&id) {
+            Some(custom) => Some(RegisteredCoin::Custom(custom)),
+            None => Coin::from_id(id).map(RegisteredCoin::Static),
+        }
+    }
+
+    /// Coin with exactly the symbol `symbol`, registered with the lowest coin type or else generated, see
+    /// [Coin::from_symbol].
+    pub fn lookup_symbol(&self, symbol: &str) -> Option<RegisteredCoin<'_>> {
+        match self.custom.values().find(|custom| custom.symbol == symbol) {
+            Some(custom) => Some(RegisteredCoin::Custom(custom)),
+            None => Coin::from_symbol(symbol).map(RegisteredCoin::Static),
+        }
+    }
+
+    /// Registered coins, ordered by coin type.
+    pub fn custom(&self) -> impl Iterator<Item = &CustomCoin> {
+        self.custom.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use crate::prelude::*;
+
+    use super::{CoinRegistry, CustomCoin, RegisteredCoin};
+    use crate::{Coin, CoinError};
+
+    fn custom(id: u32, symbol: &str, name: &str) -> CustomCoin {
+        CustomCoin {
+            id,
+            symbol: symbol.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn register() {
+        let mut registry = CoinRegistry::new();
+        assert_eq!(registry.register(2_000_000_001, "ONE", "One"), Ok(None));
+        assert_eq!(registry.register(2_000_000_000, "ZERO", "Zero"), Ok(None));
+
+        let zero = custom(2_000_000_000, "ZERO", "Zero");
+        assert_eq!(
+            registry.lookup_id(2_000_000_000),
+            Some(RegisteredCoin::Custom(&zero))
+        );
+        assert_eq!(
+            registry.lookup_symbol("ZERO"),
+            Some(RegisteredCoin::Custom(&zero))
+        );
+        assert_eq!(registry.lookup_symbol("zero"), None);
+        assert_eq!(
+            registry
+                .custom()
+                .map(|custom| custom.id)
+                .collect::<Vec<_>>(),
+            [2_000_000_000, 2_000_000_001]
+        );
+    }
+
+    #[test]
+    fn register_again() {
+        let mut registry = CoinRegistry::new();
+        registry.register(2_000_000_000, "OLD", "Old").unwrap();
+
+        assert_eq!(
+            registry.register(2_000_000_000, "NEW", "New"),
+            Ok(Some(custom(2_000_000_000, "OLD", "Old")))
+        );
+        assert_eq!(registry.lookup_symbol("OLD"), None);
+        assert_eq!(
+            registry.lookup_id(2_000_000_000),
+            Some(RegisteredCoin::Custom(&custom(2_000_000_000, "NEW", "New")))
+        );
+    }
+
+    #[test]
+    fn known_coin_type() {
+        let mut registry = CoinRegistry::new();
+        assert_eq!(
+            registry.register(0, "BTC", "Bitcoin"),
+            Err(CoinError::KnownCoinType {
+                id: 0,
+                coin: Coin::Bitcoin
+            })
+        );
+        assert_eq!(
+            registry.register(1, "TEST", "Testnet"),
+            Err(CoinError::KnownCoinType {
+                id: 1,
+                coin: Coin::Testnet
+            })
+        );
+        assert_eq!(registry.custom().count(), 0);
+    }
+
+    #[test]
+    fn fallback() {
+        let mut registry = CoinRegistry::new();
+        registry.register(2_000_000_000, "NEW", "New").unwrap();
+
+        assert_eq!(
+            registry.lookup_id(1),
+            Some(RegisteredCoin::Static(Coin::Testnet))
+        );
+        assert_eq!(
+            registry.lookup_symbol("BTC"),
+            Some(RegisteredCoin::Static(Coin::Bitcoin))
+        );
+        assert_eq!(
+            registry.lookup_id(0).map(RegisteredCoin::name),
+            Some(Coin::Bitcoin.coin_name())
+        );
+        assert_eq!(registry.lookup_id(2_000_000_001), None);
+        assert_eq!(registry.lookup_symbol("NOPE"), None);
+    }
+
+    #[test]
+    fn shadowed_symbol() {
+        let mut registry = CoinRegistry::new();
+        registry.register(2_000_000_000, "BTC", "Shadow").unwrap();
+
+        assert_eq!(
+            registry.lookup_symbol("BTC").map(RegisteredCoin::name),
+            Some("Shadow")
+        );
+        assert_eq!(
+            registry.lookup_id(0),
+            Some(RegisteredCoin::Static(Coin::Bitcoin))
+        );
+    }
+}
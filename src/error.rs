@@ -0,0 +1,121 @@
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use crate::prelude::*;
+
+/// Error returned by [Coin::try_from](crate::Coin::try_from) for a coin type that no coin has.
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
+pub struct UnknownCoinId(pub u32);
+
+impl fmt::Display for UnknownCoinId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown coin type {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownCoinId {}
+
+/// Error returned by fallible [Coin](crate::Coin) operations.
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub enum CoinError {
+    /// An invariant of the generated registry doesn't hold, see [Coin::self_check](crate::Coin::self_check).
+    InconsistentRegistry(String),
+    /// Derivation path purpose isn't one of BIP-44, BIP-49, BIP-84 or BIP-86.
+    UnknownPurpose(u32),
+    /// Account index already has the hardened bit set, see
+    /// [Coin::account_path_checked](crate::Coin::account_path_checked).
+    HardenedAccount(u32),
+    /// A symbol given for a known coin type differs from the registry's, see
+    /// [Coin::check_external_mapping](crate::Coin::check_external_mapping).
+    SymbolMismatch {
+        id: u32,
+        expected: Option<&'static str>,
+        found: String,
+    },
+    /// No coin has the symbol, see [Coin::from_symbol_unambiguous](crate::Coin::from_symbol_unambiguous).
+    UnknownSymbol(String),
+    /// Symbol of more than one coin, see [Coin::from_symbol_unambiguous](crate::Coin::from_symbol_unambiguous).
+    Ambiguous(Vec<crate::Coin>),
+    /// Coin type of a generated coin, see [CoinRegistry::register](crate::CoinRegistry::register).
+    KnownCoinType { id: u32, coin: crate::Coin },
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for CoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InconsistentRegistry(reason) => {
+                write!(f, "inconsistent coin registry: {}", reason)
+            }
+            Self::UnknownPurpose(purpose) => {
+                write!(f, "unknown derivation path purpose {}", purpose)
+            }
+            Self::HardenedAccount(account) => {
+                write!(f, "account index {:#x} is already hardened", account)
+            }
+            Self::SymbolMismatch {
+                id,
+                expected: Some(expected),
+                found,
+            } => write!(f, "coin type {} has symbol {}, not {}", id, expected, found),
+            Self::SymbolMismatch {
+                id,
+                expected: None,
+                found,
+            } => write!(f, "coin type {} has no symbol, not {}", id, found),
+            Self::UnknownSymbol(symbol) => write!(f, "unknown coin symbol {}", symbol),
+            Self::Ambiguous(coins) => write!(
+                f,
+                "symbol is shared by coins {}",
+                coins
+                    .iter()
+                    .map(|coin| coin.as_variant_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::KnownCoinType { id, coin } => {
+                write!(f, "coin type {} is already {}", id, coin.as_variant_str())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoinError {}
+
+/// Error returned when parsing a [Coin](crate::Coin) from a string fails.
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub enum ParseCoinError {
+    /// Neither a symbol nor a variant name, and shaped like a symbol, see
+    /// [SymbolQuality::passes](crate::SymbolQuality::passes).
+    UnknownSymbol(String),
+    /// Neither a symbol nor a variant name.
+    UnknownName(String),
+    /// Symbol of more than one coin, with their coin types.
+    Ambiguous { symbol: String, ids: Vec<u32> },
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseCoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol(symbol) => write!(f, "unknown coin symbol {}", symbol),
+            Self::UnknownName(name) => write!(f, "unknown coin name {}", name),
+            Self::Ambiguous { symbol, ids } => write!(
+                f,
+                "symbol {} is shared by coin types {}",
+                symbol,
+                ids.iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCoinError {}
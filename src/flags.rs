@@ -0,0 +1,65 @@
+use core::ops::BitOr;
+
+/// Set of boolean coin properties, as returned by [Coin::flags](crate::Coin::flags).
+/// ```
+/// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+/// use nil_slip44::{Coin, CoinFlags};
+///
+/// let flags = Coin::Ethereum.flags();
+/// assert!(flags.contains(CoinFlags::HAS_SYMBOL | CoinFlags::IS_EVM));
+/// assert!(!flags.is_testnet());
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Eq, Default)]
+pub struct CoinFlags(u8);
+
+impl CoinFlags {
+    /// Coin has a symbol, see [Coin::symbol_or_variant](crate::Coin::symbol_or_variant).
+    pub const HAS_SYMBOL: Self = Self(1 << 0);
+    /// Coin is the SLIP-0044 testnet shared by all coins.
+    pub const IS_TESTNET: Self = Self(1 << 1);
+    /// Coin is an EVM-compatible chain. This is curated by the crate and not part of SLIP-0044.
+    pub const IS_EVM: Self = Self(1 << 2);
+    /// Coin has more than one coin type, see [Coin::ids](crate::Coin::ids).
+    pub const IS_MULTI_ID: Self = Self(1 << 3);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn has_symbol(self) -> bool {
+        self.contains(Self::HAS_SYMBOL)
+    }
+
+    pub const fn is_testnet(self) -> bool {
+        self.contains(Self::IS_TESTNET)
+    }
+
+    pub const fn is_evm(self) -> bool {
+        self.contains(Self::IS_EVM)
+    }
+
+    pub const fn is_multi_id(self) -> bool {
+        self.contains(Self::IS_MULTI_ID)
+    }
+}
+
+impl BitOr for CoinFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
@@ -0,0 +1,31 @@
+/// Initial state of the 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash.
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Continues the 64-bit FNV-1a `hash` with `bytes`.
+pub(crate) const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fnv1a, FNV_OFFSET_BASIS};
+
+    #[test]
+    fn reference_values() {
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"foobar"), 0x8594_4171_f739_67e8);
+        assert_eq!(
+            fnv1a(fnv1a(FNV_OFFSET_BASIS, b"foo"), b"bar"),
+            fnv1a(FNV_OFFSET_BASIS, b"foobar")
+        );
+    }
+}
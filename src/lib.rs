@@ -2,10 +2,64 @@
 // Copyright (c) 2021 Alexey Shekhirin
 // SPDX-License-Identifier: MIT
 
-#![doc = include_str!("../README.md")]
+// The README examples parse coins from strings, which needs `alloc`, and use coins the bitcoin-only registry lacks.
+#![cfg_attr(
+    any(
+        all(feature = "alloc", any(feature = "full", feature = "core-coins")),
+        not(doctest)
+    ),
+    doc = include_str!("../README.md")
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(not(any(feature = "full", feature = "core-coins", feature = "bitcoin-only")))]
+compile_error!(
+    "nil-slip44 needs one of the `full` (default), `core-coins` or `bitcoin-only` features"
+);
 #[cfg(doctest)]
 pub struct ReadmeDoctests;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Items of the `std` prelude that `no_std` builds with the `alloc` feature import from `alloc`.
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+mod prelude {
+    pub(crate) use alloc::string::{String, ToString};
+    pub(crate) use alloc::vec::Vec;
+    pub(crate) use alloc::{format, vec};
+}
+
 mod coin;
+mod coin_type;
 mod coins_macro;
+#[cfg(feature = "alloc")]
+mod custom;
+mod error;
+mod flags;
+mod hash;
+mod overlay;
+mod path;
+mod quality;
+mod registry;
+#[cfg(feature = "alloc")]
+mod search;
 pub use coin::*;
+pub use coin_type::CoinType;
+#[cfg(feature = "alloc")]
+pub use custom::{CoinRegistry, CustomCoin, RegisteredCoin};
+pub use error::UnknownCoinId;
+#[cfg(feature = "alloc")]
+pub use error::{CoinError, ParseCoinError};
+pub use flags::CoinFlags;
+pub use path::HardenedStyle;
+pub use quality::SymbolQuality;
+#[cfg(feature = "digest")]
+pub use registry::registry_digest;
+pub use registry::{base_symbol, is_compatible_with, is_known_symbol};
+
+#[cfg(feature = "serde")]
+pub mod serde_name;
+#[cfg(feature = "serde")]
+pub mod serde_symbol;
+#[cfg(feature = "wasm")]
+pub mod wasm;
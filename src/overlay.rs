@@ -0,0 +1,158 @@
+//! Coin metadata curated by this crate on top of SLIP-0044.
+//!
+//! Nothing here comes from the [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md)
+//! registry: the tables are maintained by hand and keyed by coin type, so they survive
+//! regeneration of `coin.rs`.
+
+/// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain IDs of EVM-compatible chains, by coin type.
+///
+/// Also the list of chains [Coin::is_evm](crate::Coin::is_evm) is true for, so add new EVM-compatible
+/// chains here.
+pub(crate) const EVM_CHAIN_IDS: &[(u32, u64)] = &[
+    (40, 2),          // Expanse
+    (60, 1),          // Ethereum
+    (61, 61),         // Ethereum Classic
+    (108, 8),         // Ubiq
+    (137, 30),        // Rootstock
+    (163, 64),        // Ellaism
+    (178, 99),        // POA
+    (246, 246),       // Energy Web
+    (614, 10),        // Optimism
+    (642, 321),       // KuCoin Community Chain
+    (700, 100),       // xDai
+    (804, 324),       // zkSync
+    (820, 820),       // Callisto
+    (889, 88),        // TOMO
+    (966, 137),       // Polygon
+    (996, 66),        // OKChain
+    (1001, 108),      // ThunderCore
+    (1007, 250),      // Fantom
+    (1010, 128),      // Huobi ECO Chain
+    (1284, 1284),     // Moonbeam
+    (1285, 1285),     // Moonriver
+    (8217, 8217),     // Kaia
+    (8453, 8453),     // Base
+    (9001, 42161),    // Arbitrum
+    (9002, 288),      // Boba
+    (9006, 56),       // BNB Smart Chain
+    (18000, 82),      // Meter
+    (52752, 42220),   // Celo
+    (534352, 534352), // Scroll
+    (5718350, 888),   // Wanchain
+];
+
+/// [CAIP-2](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-2.md) chain IDs of non-EVM chains, by coin
+/// type. EVM-compatible chains are `eip155:<chain ID>` from [EVM_CHAIN_IDS].
+#[cfg(feature = "caip")]
+pub(crate) const CAIP2_CHAIN_IDS: &[(u32, &str)] = &[
+    (0, "bip122:000000000019d6689c085ae165831e93"), // Bitcoin
+    (1, "bip122:000000000933ea01ad0ee984209779ba"), // Bitcoin Testnet
+    (2, "bip122:12a765e31ffd4059bada1e25190f6e98"), // Litecoin
+    (3, "bip122:1a91e3dace36e2be3bf030a65679fe82"), // Dogecoin
+    (118, "cosmos:cosmoshub-4"),                    // Cosmos Hub
+    (148, "stellar:pubnet"),                        // Stellar
+    (354, "polkadot:91b171bb158e2d3848fa23a9f1c25182"), // Polkadot
+    (434, "polkadot:b0a8d493285c2df73290dfb7e61f870f"), // Kusama
+    (501, "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"), // Solana
+    (1729, "tezos:NetXdQprcVkpaWU"),                // Tezos
+];
+
+/// [CAIP-2](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-2.md) namespaces of the chains in
+/// [CAIP2_CHAIN_IDS], by coin type. EVM-compatible chains are `eip155`.
+#[cfg(feature = "caip")]
+pub(crate) const CAIP2_NAMESPACES: &[(u32, &str)] = &[
+    (0, "bip122"),     // Bitcoin
+    (1, "bip122"),     // Bitcoin Testnet
+    (2, "bip122"),     // Litecoin
+    (3, "bip122"),     // Dogecoin
+    (118, "cosmos"),   // Cosmos Hub
+    (148, "stellar"),  // Stellar
+    (354, "polkadot"), // Polkadot
+    (434, "polkadot"), // Kusama
+    (501, "solana"),   // Solana
+    (1729, "tezos"),   // Tezos
+];
+
+/// SegWit support of Bitcoin-family chains, by coin type.
+pub(crate) const SEGWIT_SUPPORT: &[(u32, bool)] = &[
+    (0, true),    // Bitcoin
+    (2, true),    // Litecoin
+    (3, false),   // Dogecoin
+    (5, false),   // Dash
+    (8, true),    // Feathercoin
+    (14, true),   // Viacoin
+    (17, true),   // Groestlcoin
+    (20, true),   // DigiByte
+    (22, true),   // Monacoin
+    (28, true),   // Vertcoin
+    (145, false), // Bitcoin Cash
+    (156, true),  // Bitcoin Gold
+    (236, false), // Bitcoin SV
+];
+
+/// Coin types of chains natively hosting USDT or USDC. Advisory only.
+#[cfg(feature = "stablecoins")]
+pub(crate) const MAJOR_STABLECOIN_HOSTS: &[u32] = &[
+    60,    // Ethereum
+    148,   // Stellar
+    195,   // Tron
+    283,   // Algorand
+    397,   // NEAR
+    501,   // Solana
+    607,   // TON
+    614,   // Optimism
+    637,   // Aptos
+    784,   // Sui
+    966,   // Polygon
+    8453,  // Base
+    9000,  // Avalanche
+    9001,  // Arbitrum
+    9006,  // BNB Smart Chain
+    52752, // Celo
+];
+
+/// Coin types tracked by wrapped or pegged coins, by coin type.
+#[cfg(feature = "relations")]
+pub(crate) const UNDERLYING: &[(u32, u32)] = &[
+    (137, 0),   // Rootstock smart bitcoin, pegged to Bitcoin
+    (653, 651), // Wrapped Safex Cash
+    (1776, 0),  // Liquid BTC, pegged to Bitcoin
+];
+
+/// Variant names that no longer exist, by the coin type they now resolve to.
+///
+/// Append an entry whenever a regeneration or a change to the naming rules renames a variant. No variant has been
+/// renamed yet.
+#[cfg(not(test))]
+pub(crate) const LEGACY_NAMES: &[(&str, u32)] = &[];
+
+/// Fixture for the unit tests, valid in every registry.
+#[cfg(test)]
+pub(crate) const LEGACY_NAMES: &[(&str, u32)] = &[("OldBitcoin", 0)];
+
+/// Ticker prefixes of wrapped, staked or bridged tokens, e.g. the `W` in `WBTC` or the `st` in `stETH`, stripped by
+/// [base_symbol](crate::base_symbol).
+pub(crate) const WRAPPER_PREFIXES: &[&str] = &["W", "st", "cb", "a"];
+
+pub(crate) const fn lookup<T: Copy>(table: &[(u32, T)], id: u32) -> Option<T> {
+    let mut i = 0;
+    while i < table.len() {
+        if table[i].0 == id {
+            return Some(table[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(feature = "stablecoins")]
+pub(crate) const fn contains(table: &[u32], id: u32) -> bool {
+    let mut i = 0;
+    while i < table.len() {
+        if table[i] == id {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
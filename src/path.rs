@@ -0,0 +1,33 @@
+//! Building blocks for BIP-32 derivation paths.
+
+/// Offset added to a path component to make it hardened.
+pub(crate) const HARDENED: u32 = 0x8000_0000;
+
+/// Coin type shared by the testnets of all coins.
+pub(crate) const TESTNET_COIN_TYPE: u32 = 1;
+
+/// Purpose components defined by BIP-44, BIP-49, BIP-84 and BIP-86.
+#[cfg(feature = "alloc")]
+pub(crate) const PURPOSES: &[u32] = &[44, 49, 84, 86];
+
+/// Marker used to write hardened path components, e.g. the `'` in `m/44'`.
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
+pub enum HardenedStyle {
+    /// `44'`
+    Apostrophe,
+    /// `44h`
+    LowerH,
+    /// `44H`
+    UpperH,
+}
+
+impl HardenedStyle {
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn marker(self) -> char {
+        match self {
+            Self::Apostrophe => '\'',
+            Self::LowerH => 'h',
+            Self::UpperH => 'H',
+        }
+    }
+}
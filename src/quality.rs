@@ -0,0 +1,58 @@
+/// Ticker conventions met by a coin symbol, as returned by [Coin::symbol_quality](crate::Coin::symbol_quality).
+/// ```
+/// use nil_slip44::SymbolQuality;
+///
+/// assert!(SymbolQuality::of("BTC").passes());
+/// assert!(!SymbolQuality::of("kUSD").is_uppercase);
+/// assert!(!SymbolQuality::of("_8BIT").is_alphanumeric);
+/// assert!(!SymbolQuality::of("Evrynet").len_ok);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
+pub struct SymbolQuality {
+    /// Symbol has no lowercase letters.
+    pub is_uppercase: bool,
+    /// Symbol consists of ASCII letters and digits only.
+    pub is_alphanumeric: bool,
+    /// Symbol is non-empty and at most [SymbolQuality::MAX_LEN] bytes long.
+    pub len_ok: bool,
+}
+
+impl SymbolQuality {
+    /// Longest conventional symbol length in bytes.
+    pub const MAX_LEN: usize = 6;
+
+    /// Checks `symbol` against ticker conventions.
+    pub fn of(symbol: &str) -> Self {
+        Self {
+            is_uppercase: !symbol.chars().any(char::is_lowercase),
+            is_alphanumeric: symbol.chars().all(|ch| ch.is_ascii_alphanumeric()),
+            len_ok: (1..=Self::MAX_LEN).contains(&symbol.len()),
+        }
+    }
+
+    /// Whether all conventions are met.
+    pub const fn passes(self) -> bool {
+        self.is_uppercase && self.is_alphanumeric && self.len_ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SymbolQuality;
+
+    #[test]
+    fn conventions() {
+        assert!(SymbolQuality::of("BTC").passes());
+        assert!(SymbolQuality::of("1INCH").passes());
+        assert_eq!(
+            SymbolQuality::of("b-t.c!!"),
+            SymbolQuality {
+                is_uppercase: false,
+                is_alphanumeric: false,
+                len_ok: false,
+            }
+        );
+        assert!(!SymbolQuality::of("").len_ok);
+        assert!(!SymbolQuality::of("ÉTH").is_alphanumeric);
+    }
+}
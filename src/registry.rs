@@ -0,0 +1,232 @@
+use crate::overlay::WRAPPER_PREFIXES;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use crate::CoinError;
+use crate::{Coin, REGISTRY_VERSION};
+
+/// Whether a registry reporting `version` has the same coins as this one, e.g. to check that a client
+/// and a server agree on the coin table.
+///
+/// Registry versions are opaque; only identical versions are compatible.
+/// ```
+/// use nil_slip44::{is_compatible_with, REGISTRY_VERSION};
+///
+/// assert!(!REGISTRY_VERSION.is_empty());
+/// assert!(is_compatible_with(REGISTRY_VERSION));
+/// assert!(!is_compatible_with("0+master"));
+/// ```
+pub fn is_compatible_with(version: &str) -> bool {
+    version == REGISTRY_VERSION
+}
+
+/// Whether any coin has the symbol `symbol`, compared case-insensitively, duplicate symbols
+/// (see [Coin::duplicate_symbol]) included.
+/// ```
+/// # #[cfg(feature = "full")] {
+/// use nil_slip44::is_known_symbol;
+///
+/// assert!(is_known_symbol("BTC"));
+/// assert!(is_known_symbol("eth"));
+/// assert!(is_known_symbol("BSC"));
+/// assert!(!is_known_symbol("NOTACOIN"));
+/// # }
+/// ```
+pub fn is_known_symbol(symbol: &str) -> bool {
+    Coin::all()
+        .filter_map(Coin::raw_symbol)
+        .any(|known| known.eq_ignore_ascii_case(symbol))
+}
+
+/// SHA-256 digest of every coin's IDs, variant name, SLIP-0044 name and symbol, to check that two builds embed the
+/// same registry in one comparison. Unlike [REGISTRY_VERSION] it also tells apart registries generated from the same
+/// SLIP-0044 revision with different naming rules, or edited by hand.
+/// ```
+/// use nil_slip44::registry_digest;
+///
+/// assert_eq!(registry_digest(), registry_digest());
+/// ```
+#[cfg(feature = "digest")]
+pub fn registry_digest() -> [u8; 32] {
+    digest(Coin::all().map(|coin| {
+        let symbol = coin
+            .symbol()
+            .map(str::to_string)
+            .or(coin.duplicate_symbol());
+        (coin.ids(), coin.as_variant_str(), coin.coin_name(), symbol)
+    }))
+}
+
+/// Digest of coins given as IDs, variant name, SLIP-0044 name and symbol, see [registry_digest].
+#[cfg(feature = "digest")]
+fn digest<'a>(
+    coins: impl IntoIterator<Item = (&'a [u32], &'a str, &'a str, Option<String>)>,
+) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    // Strings are length-prefixed so that no two registries hash the same bytes.
+    fn update_str(hasher: &mut Sha256, s: &str) {
+        hasher.update((s.len() as u64).to_be_bytes());
+        hasher.update(s);
+    }
+
+    let mut hasher = Sha256::new();
+    for (ids, variant, name, symbol) in coins {
+        hasher.update((ids.len() as u64).to_be_bytes());
+        for id in ids {
+            hasher.update(id.to_be_bytes());
+        }
+        update_str(&mut hasher, variant);
+        update_str(&mut hasher, name);
+        match symbol {
+            Some(symbol) => {
+                hasher.update([1]);
+                update_str(&mut hasher, &symbol);
+            }
+            None => hasher.update([0]),
+        }
+    }
+    hasher.finalize().into()
+}
+
+/// `symbol` without a wrapper prefix such as `W` or `st` (see [Coin::base_symbol]), if what remains is the
+/// [symbol](Coin::symbol) of a coin, or else `symbol` as is.
+///
+/// This is a heuristic to match e.g. wrapped tokens to the coin they wrap, some coins just happen to have a symbol
+/// starting with a prefix.
+/// ```
+/// # #[cfg(any(feature = "full", feature = "core-coins"))] {
+/// use nil_slip44::base_symbol;
+///
+/// assert_eq!(base_symbol("WBTC"), "BTC");
+/// assert_eq!(base_symbol("stETH"), "ETH");
+/// assert_eq!(base_symbol("cbBTC"), "BTC");
+/// assert_eq!(base_symbol("WICC"), "WICC");
+/// # }
+/// ```
+pub fn base_symbol(symbol: &str) -> &str {
+    WRAPPER_PREFIXES
+        .iter()
+        .filter_map(|prefix| symbol.strip_prefix(prefix))
+        .find(|base| Coin::from_symbol(base).is_some())
+        .unwrap_or(symbol)
+}
+
+#[cfg(feature = "alloc")]
+impl Coin {
+    /// Whether more than one coin has the exact symbol `symbol`, duplicate symbols
+    /// (see [Coin::duplicate_symbol]) included.
+    /// ```
+    /// # #[cfg(feature = "full")] {
+    /// use nil_slip44::Coin;
+    ///
+    /// assert!(Coin::symbol_is_ambiguous("CPC"));
+    /// assert!(!Coin::symbol_is_ambiguous("BTC"));
+    /// assert!(!Coin::symbol_is_ambiguous("NOPE"));
+    /// # }
+    /// ```
+    pub fn symbol_is_ambiguous(symbol: &str) -> bool {
+        Coin::coins_for_symbol(symbol).nth(1).is_some()
+    }
+
+    /// Coin by its exact symbol, duplicate symbols (see [Coin::duplicate_symbol]) included, failing with
+    /// [CoinError::Ambiguous] instead of picking one of the coins sharing the symbol like [Coin::from_symbol] does.
+    ///
+    /// Fails with [CoinError::UnknownSymbol] if no coin has the symbol.
+    /// ```
+    /// # #[cfg(feature = "full")] {
+    /// use nil_slip44::{Coin, CoinError};
+    ///
+    /// assert_eq!(Coin::from_symbol_unambiguous("BTC"), Ok(Coin::Bitcoin));
+    /// assert_eq!(
+    ///     Coin::from_symbol_unambiguous("CPC"),
+    ///     Err(CoinError::Ambiguous(vec![Coin::Capricoin, Coin::CPChain])),
+    /// );
+    /// assert_eq!(
+    ///     Coin::from_symbol_unambiguous("NOPE"),
+    ///     Err(CoinError::UnknownSymbol("NOPE".to_string())),
+    /// );
+    /// # }
+    /// ```
+    pub fn from_symbol_unambiguous(symbol: &str) -> Result<Coin, CoinError> {
+        let mut coins = Coin::coins_for_symbol(symbol);
+        match (coins.next(), coins.next()) {
+            (Some(first), Some(second)) => Err(CoinError::Ambiguous(
+                [first, second].into_iter().chain(coins).collect(),
+            )),
+            (Some(coin), None) => Ok(coin),
+            (None, _) => Err(CoinError::UnknownSymbol(symbol.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn digest() {
+        use super::digest;
+
+        let coins = || {
+            vec![
+                (&[0, 500][..], "Bitcoin", "Bitcoin", Some("BTC".to_string())),
+                (&[1], "Testnet", "Testnet (all coins)", None),
+            ]
+        };
+        assert_eq!(digest(coins()), digest(coins()));
+
+        let mut renamed = coins();
+        renamed[1].2 = "Testnet";
+        assert_ne!(digest(renamed), digest(coins()));
+
+        let mut without_id = coins();
+        without_id[0].0 = &[0];
+        assert_ne!(digest(without_id), digest(coins()));
+
+        let mut with_symbol = coins();
+        with_symbol[1].3 = Some(String::new());
+        assert_ne!(digest(with_symbol), digest(coins()));
+
+        assert_ne!(digest(coins().into_iter().take(1)), digest(coins()));
+        assert_ne!(crate::registry_digest(), digest(coins()));
+    }
+
+    // SFX is only in the full registry.
+    #[cfg(feature = "full")]
+    #[test]
+    fn base_symbol() {
+        use crate::base_symbol;
+
+        assert_eq!(base_symbol("aETH"), "ETH");
+        assert_eq!(base_symbol("WSFX"), "SFX");
+        assert_eq!(base_symbol("STETH"), "STETH");
+        assert_eq!(base_symbol("WEST"), "WEST");
+        assert_eq!(base_symbol("W"), "W");
+        assert_eq!(base_symbol(""), "");
+    }
+
+    // Capricoin and CPChain are only in the full registry.
+    #[cfg(all(feature = "full", feature = "alloc"))]
+    #[test]
+    fn from_symbol_unambiguous() {
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        use crate::prelude::*;
+        use crate::{Coin, CoinError};
+
+        assert_eq!(Coin::from_symbol_unambiguous("ETH"), Ok(Coin::Ethereum));
+        assert!(!Coin::symbol_is_ambiguous("ETH"));
+
+        let err = Coin::from_symbol_unambiguous("CPC").unwrap_err();
+        assert_eq!(
+            err,
+            CoinError::Ambiguous(vec![Coin::Capricoin, Coin::CPChain])
+        );
+        assert_eq!(
+            err.to_string(),
+            "symbol is shared by coins Capricoin, CPChain"
+        );
+        assert!(Coin::symbol_is_ambiguous("CPC"));
+        assert_eq!(Coin::from_symbol("CPC"), Some(Coin::Capricoin));
+    }
+}
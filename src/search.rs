@@ -0,0 +1,74 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Number of single-character insertions, deletions or substitutions turning `a` into `b`.
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Similarity of `a` and `b` ignoring case, from 0.0 (nothing in common) to 1.0 (equal).
+pub(crate) fn similarity(a: &str, b: &str) -> f32 {
+    let (a, b) = (a.to_lowercase(), b.to_lowercase());
+    let len = a.chars().count().max(b.chars().count());
+    if len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(&a, &b) as f32 / len as f32
+}
+
+/// How well `field` matches the lowercase `query`: 0 if it starts with it, 1 if it contains it elsewhere, ignoring
+/// case.
+pub(crate) fn match_rank(query: &str, field: &str) -> Option<u8> {
+    let field = field.to_lowercase();
+    if field.starts_with(query) {
+        Some(0)
+    } else if field.contains(query) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{levenshtein, match_rank, similarity};
+
+    #[test]
+    fn distance() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("BTC", "BTC"), 0);
+        assert_eq!(levenshtein("BTC", ""), 3);
+        assert_eq!(levenshtein("BTC", "BCH"), 2);
+        assert_eq!(levenshtein("ETH", "ETC"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn normalized_similarity() {
+        assert_eq!(similarity("", ""), 1.0);
+        assert_eq!(similarity("btc", "BTC"), 1.0);
+        assert_eq!(similarity("abc", "xyz"), 0.0);
+        assert_eq!(similarity("ETCH", "ETH"), 0.75);
+    }
+
+    #[test]
+    fn rank() {
+        assert_eq!(match_rank("bit", "Bitcoin"), Some(0));
+        assert_eq!(match_rank("coin", "Bitcoin"), Some(1));
+        assert_eq!(match_rank("eth", "BTC"), None);
+        assert_eq!(match_rank("", "BTC"), Some(0));
+    }
+}
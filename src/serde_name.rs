@@ -0,0 +1,126 @@
+//! Serialize [Coin] as the name of its variant (see [Coin::name]), deserializing with the exact
+//! [Coin::from_name].
+//!
+//! Unlike symbols, no two coins share a variant name, and unlike coin types they're readable, which
+//! makes them the key to use in hand-written config files. Variant names are derived from the
+//! SLIP-0044 coin names by `parse-coins`, but renaming one is a breaking change, and the former name
+//! stays available to [Coin::from_legacy_name]. Deserializing a legacy name, a symbol or a SLIP-0044
+//! name fails with an error naming the variant meant.
+//! ```
+//! # #[cfg(feature = "full")] {
+//! use nil_slip44::Coin;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Wallet {
+//!     #[serde(with = "nil_slip44::serde_name")]
+//!     coin: Coin,
+//! }
+//!
+//! let json = serde_json::to_string(&Wallet { coin: Coin::UniformFiscalObject }).unwrap();
+//! assert_eq!(json, r#"{"coin":"UniformFiscalObject"}"#);
+//! assert_eq!(serde_json::from_str::<Wallet>(&json).unwrap(), Wallet { coin: Coin::UniformFiscalObject });
+//!
+//! let err = serde_json::from_str::<Wallet>(r#"{"coin":"Uniform Fiscal Object"}"#).unwrap_err();
+//! assert!(err.to_string().contains("did you mean `UniformFiscalObject`?"));
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, Unexpected, Visitor};
+use serde::Serializer;
+
+use crate::Coin;
+
+pub fn serialize<S: Serializer>(coin: &Coin, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(coin.name())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Coin, D::Error> {
+    deserializer.deserialize_str(VariantNameVisitor)
+}
+
+struct VariantNameVisitor;
+
+impl Visitor<'_> for VariantNameVisitor {
+    type Value = Coin;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a SLIP-0044 coin variant name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Coin::from_name(v).ok_or_else(|| match meant(v) {
+            Some(coin) => E::custom(format_args!(
+                "unknown coin variant name `{}`, did you mean `{}`?",
+                v,
+                coin.name()
+            )),
+            None => E::invalid_value(Unexpected::Str(v), &self),
+        })
+    }
+}
+
+/// Coin that `name` likely refers to without being its variant name: a variant name in another case,
+/// a legacy variant name, a SLIP-0044 name or a symbol, in that order.
+fn meant(name: &str) -> Option<Coin> {
+    Coin::all()
+        .find(|coin| coin.name().eq_ignore_ascii_case(name))
+        .or_else(|| Coin::from_legacy_name(name))
+        .or_else(|| Coin::from_coin_name(name))
+        .or_else(|| Coin::from_symbol(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::Coin;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Holder(#[serde(with = "super")] Coin);
+
+    #[test]
+    fn round_trip() {
+        for coin in Coin::all() {
+            let json = serde_json::to_string(&Holder(coin)).unwrap();
+            assert_eq!(json, format!("\"{}\"", coin.as_variant_str()));
+            assert_eq!(serde_json::from_str::<Holder>(&json).unwrap(), Holder(coin));
+        }
+    }
+
+    #[test]
+    fn unknown_name() {
+        let err = serde_json::from_str::<Holder>("\"NotACoin\"").unwrap_err();
+        assert!(err.to_string().contains("a SLIP-0044 coin variant name"));
+    }
+
+    fn assert_meant(name: &str, meant: &str) {
+        let err = serde_json::from_str::<Holder>(&format!("\"{}\"", name)).unwrap_err();
+        assert!(
+            err.to_string().contains(&format!(
+                "unknown coin variant name `{}`, did you mean `{}`?",
+                name, meant
+            )),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn meant_name() {
+        assert_meant("bitcoin", "Bitcoin");
+        assert_meant("Testnet (all coins)", "Testnet");
+        assert_meant("BTC", "Bitcoin");
+    }
+
+    // Ether and Uniform Fiscal Object are only in the full registry.
+    #[cfg(feature = "full")]
+    #[test]
+    fn meant_renamed() {
+        assert_meant("ethereum", "Ethereum");
+        assert_meant("Ether", "Ethereum");
+        assert_meant("Uniform Fiscal Object", "UniformFiscalObject");
+    }
+}
@@ -0,0 +1,96 @@
+//! Serialize [Coin] as its symbol (see [Coin::symbol]).
+//!
+//! Only coins with a symbol linked to [Symbol](crate::Symbol) can be serialized this way, coins
+//! without a symbol or with only a [duplicate](Coin::duplicate_symbol) one fail to serialize.
+//! ```
+//! # #[cfg(feature = "full")] {
+//! use nil_slip44::Coin;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Wallet {
+//!     #[serde(with = "nil_slip44::serde_symbol")]
+//!     coin: Coin,
+//! }
+//!
+//! let json = serde_json::to_string(&Wallet { coin: Coin::Ethereum }).unwrap();
+//! assert_eq!(json, r#"{"coin":"ETH"}"#);
+//! assert_eq!(serde_json::from_str::<Wallet>(&json).unwrap(), Wallet { coin: Coin::Ethereum });
+//!
+//! assert!(serde_json::to_string(&Wallet { coin: Coin::OpenAssets }).is_err());
+//! assert!(serde_json::from_str::<Wallet>(r#"{"coin":"eth"}"#).is_err());
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, Unexpected, Visitor};
+use serde::ser::{self, Serializer};
+
+use crate::Coin;
+
+pub fn serialize<S: Serializer>(coin: &Coin, serializer: S) -> Result<S::Ok, S::Error> {
+    match coin.symbol() {
+        Some(symbol) => serializer.serialize_str(symbol),
+        None => Err(ser::Error::custom(format_args!(
+            "coin {} has no symbol",
+            coin.as_variant_str()
+        ))),
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Coin, D::Error> {
+    deserializer.deserialize_str(SymbolVisitor)
+}
+
+struct SymbolVisitor;
+
+impl Visitor<'_> for SymbolVisitor {
+    type Value = Coin;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a SLIP-0044 coin symbol")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Coin::from_symbol(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::Coin;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Holder(#[serde(with = "super")] Coin);
+
+    #[test]
+    fn round_trip() {
+        for coin in Coin::all().filter(|coin| coin.symbol().is_some()) {
+            let json = serde_json::to_string(&Holder(coin)).unwrap();
+            assert_eq!(json, format!("\"{}\"", coin.symbol().unwrap()));
+            assert_eq!(serde_json::from_str::<Holder>(&json).unwrap(), Holder(coin));
+        }
+    }
+
+    #[test]
+    fn no_symbol() {
+        let err = serde_json::to_string(&Holder(Coin::Testnet)).unwrap_err();
+        assert!(err.to_string().contains("has no symbol"));
+    }
+
+    // CPChain is only in the full registry.
+    #[cfg(feature = "full")]
+    #[test]
+    fn duplicate_symbol() {
+        assert!(serde_json::to_string(&Holder(Coin::CPChain)).is_err());
+    }
+
+    #[test]
+    fn unknown_symbol() {
+        let err = serde_json::from_str::<Holder>("\"NOPE\"").unwrap_err();
+        assert!(err.to_string().contains("a SLIP-0044 coin symbol"));
+    }
+}
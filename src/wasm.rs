@@ -0,0 +1,53 @@
+//! Coin lookups exported to JavaScript with [wasm-bindgen](https://docs.rs/wasm-bindgen).
+//!
+//! Built for `wasm32-unknown-unknown`, e.g. with `wasm-pack build --features wasm`, these are plain functions
+//! callable from JS as `coin_from_id(60)` and `coin_id_from_symbol("ETH")`. On other targets they're ordinary Rust
+//! functions.
+//! ```
+//! # #[cfg(any(feature = "full", feature = "core-coins"))] {
+//! use nil_slip44::wasm::{coin_from_id, coin_id_from_symbol};
+//!
+//! assert_eq!(coin_from_id(60), Some("ETH".to_string()));
+//! assert_eq!(coin_id_from_symbol("ETH"), Some(60));
+//! # }
+//! ```
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::Coin;
+
+/// Symbol of the coin with the given ID, or the name of its variant if it has no symbol, see
+/// [Coin::from_id] and [Coin::symbol_or_variant].
+#[wasm_bindgen]
+pub fn coin_from_id(id: u32) -> Option<String> {
+    Coin::from_id(id).map(|coin| coin.symbol_or_variant().to_string())
+}
+
+/// Primary ID of the coin with the given symbol, see [Coin::from_symbol].
+#[wasm_bindgen]
+pub fn coin_id_from_symbol(symbol: &str) -> Option<u32> {
+    Coin::from_symbol(symbol).map(Coin::id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coin_from_id, coin_id_from_symbol};
+
+    #[test]
+    fn lookups() {
+        assert_eq!(coin_from_id(0), Some("BTC".to_string()));
+        assert_eq!(coin_from_id(1), Some("Testnet".to_string()));
+        assert_eq!(coin_from_id(u32::MAX), None);
+
+        assert_eq!(coin_id_from_symbol("BTC"), Some(0));
+        assert_eq!(coin_id_from_symbol("btc"), None);
+    }
+
+    // Credits is only in the full registry.
+    #[cfg(feature = "full")]
+    #[test]
+    fn secondary_id() {
+        assert_eq!(coin_from_id(498), Some("CS".to_string()));
+        assert_eq!(coin_id_from_symbol("CS"), Some(334));
+    }
+}
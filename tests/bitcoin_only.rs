@@ -0,0 +1,28 @@
+//! The minimal registry built with `bitcoin-only` instead of `full` or `core-coins`, run with
+//! `cargo test --no-default-features --features std,bitcoin-only --test bitcoin_only`.
+#![cfg(all(not(any(feature = "full", feature = "core-coins")), feature = "std"))]
+
+use nil_slip44::{Coin, Symbol, COIN_COUNT};
+
+#[test]
+fn bitcoin_and_testnet_are_included() {
+    assert_eq!(Coin::from_id(0), Some(Coin::Bitcoin));
+    assert_eq!(Coin::from_id(1), Some(Coin::Testnet));
+    assert_eq!(Coin::from_symbol("BTC"), Some(Coin::Bitcoin));
+    assert_eq!(Coin::from(Symbol::BTC), Coin::Bitcoin);
+    assert_eq!(Coin::self_check(), Ok(()));
+}
+
+#[test]
+fn registry_is_bitcoin_only() {
+    assert_eq!(COIN_COUNT, 2);
+    assert_eq!(Coin::ALL, [Coin::Bitcoin, Coin::Testnet]);
+    assert_eq!(Coin::all().collect::<Vec<_>>(), Coin::ALL);
+    assert_eq!(Coin::common(), vec![Coin::Bitcoin]);
+    assert_eq!(Coin::MAX_COIN_TYPE, 1);
+    assert_eq!(Coin::MAX_ID_DIGITS, 1);
+
+    assert_eq!(Coin::from_id(60), None);
+    assert_eq!(Coin::from_symbol("ETH"), None);
+    assert_eq!(Coin::from_variant_str("Ethereum"), None);
+}
@@ -0,0 +1,36 @@
+//! The trimmed registry built with `core-coins` instead of `full`, run with
+//! `cargo test --no-default-features --features std,core-coins --test core_coins`.
+#![cfg(all(not(feature = "full"), feature = "core-coins", feature = "std"))]
+
+use nil_slip44::{Coin, Symbol, COIN_COUNT};
+
+#[test]
+fn core_coins_are_included() {
+    for (id, coin) in [
+        (0, Coin::Bitcoin),
+        (1, Coin::Testnet),
+        (60, Coin::Ethereum),
+        (501, Coin::Solana),
+    ] {
+        assert_eq!(Coin::from_id(id), Some(coin));
+        assert_eq!(coin.id(), id);
+    }
+    assert_eq!(Coin::from_symbol("ETH"), Some(Coin::Ethereum));
+    assert_eq!(Coin::from(Symbol::BTC), Coin::Bitcoin);
+    assert_eq!(Coin::self_check(), Ok(()));
+}
+
+#[test]
+fn other_coins_are_absent() {
+    assert!(Coin::all().len() < 50);
+    assert_eq!(COIN_COUNT, Coin::all().count());
+    assert_eq!(Coin::all().collect::<Vec<_>>(), Coin::ALL);
+    for coin in Coin::common() {
+        assert!(Coin::ALL.contains(&coin));
+    }
+
+    assert_eq!(Coin::from_id(334), None);
+    assert_eq!(Coin::from_variant_str("Credits"), None);
+    assert_eq!(Coin::from_symbol("CS"), None);
+    assert_eq!(Coin::from_id(5718350), None);
+}
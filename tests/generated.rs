@@ -0,0 +1,186 @@
+//! `src/coin.rs` is produced by `parse-coins` and must not be edited by hand.
+//!
+//! These tests check the full registry, see `tests/core_coins.rs` for the trimmed one.
+#![cfg(feature = "full")]
+
+use nil_slip44::{Coin, COIN_COUNT, REGISTRY_VERSION, SLIP44_SOURCE_REF};
+
+const COIN_RS: &str = include_str!("../src/coin.rs");
+const GENERATED_BANNER: &str = "// Code generated by src/bin/parse_coins.rs; DO NOT EDIT.";
+
+#[test]
+fn coin_rs_starts_with_generated_banner() {
+    assert_eq!(COIN_RS.lines().next(), Some(GENERATED_BANNER));
+}
+
+#[test]
+fn coin_rs_records_its_source() {
+    let source = COIN_RS.lines().nth(1).unwrap();
+
+    assert!(source.starts_with(&format!(
+        "// SLIP-0044 source: {}, generated at Unix time ",
+        SLIP44_SOURCE_REF
+    )));
+    assert!(COIN_RS.contains(&format!(
+        "\npub const SLIP44_SOURCE_REF: &str = \"{}\";\n",
+        SLIP44_SOURCE_REF
+    )));
+    assert!(REGISTRY_VERSION.ends_with(&format!("+{}", SLIP44_SOURCE_REF)));
+}
+
+/// Lines of the `coins!` invocation of the full registry, without the trimmed one.
+fn full_registry() -> impl Iterator<Item = &'static str> {
+    COIN_RS.lines().skip(4).take_while(|line| *line != ");")
+}
+
+#[test]
+fn coin_rs_has_a_full_a_core_coins_and_a_bitcoin_only_invocation() {
+    let body: Vec<_> = COIN_RS.lines().skip(2).collect();
+
+    assert_eq!(body.first(), Some(&"use crate::coins;"));
+    assert_eq!(body.get(1), Some(&"#[cfg(feature = \"full\")]"));
+    assert_eq!(body.get(2), Some(&"coins!("));
+    assert_eq!(body.iter().filter(|line| **line == "coins!(").count(), 3);
+    assert_eq!(body.iter().filter(|line| **line == ");").count(), 3);
+
+    for cfg in [
+        "#[cfg(all(not(feature = \"full\"), feature = \"core-coins\"))]",
+        "#[cfg(not(any(feature = \"full\", feature = \"core-coins\")))]",
+    ] {
+        let registry = body.iter().position(|line| *line == cfg).unwrap();
+        assert_eq!(body.get(registry + 1), Some(&"coins!("), "{}", cfg);
+    }
+}
+
+#[test]
+fn max_coin_type() {
+    let max = Coin::all().flat_map(Coin::ids).copied().max();
+
+    assert_eq!(Some(Coin::MAX_COIN_TYPE), max);
+    assert_eq!(Coin::MAX_ID_DIGITS, Coin::MAX_COIN_TYPE.to_string().len());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn common_coins() {
+    let common = Coin::common();
+
+    assert!(!common.is_empty());
+    assert_eq!(common[..2], [Coin::Bitcoin, Coin::Ethereum]);
+    assert!(common.iter().all(|coin| Coin::all().any(|c| c == *coin)));
+    assert!(common
+        .iter()
+        .enumerate()
+        .all(|(i, coin)| !common[..i].contains(coin)));
+}
+
+#[test]
+fn legacy_names_are_not_current() {
+    for coin in Coin::all() {
+        assert_eq!(Coin::from_legacy_name(coin.name()), None);
+    }
+}
+
+#[test]
+fn id_index_covers_every_id() {
+    assert!(Coin::ID_INDEX.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    for coin in Coin::all() {
+        for &id in coin.ids() {
+            assert!(Coin::ID_INDEX.contains(&(id, coin)));
+            assert_eq!(Coin::from_id(id), Some(coin));
+        }
+    }
+}
+
+#[test]
+fn discriminant_round_trips() {
+    for coin in Coin::all() {
+        assert_eq!(coin.discriminant(), coin.id());
+        assert_eq!(Coin::from_id(coin.id()), Some(coin), "{:?}", coin);
+    }
+}
+
+#[test]
+fn indices_are_dense() {
+    let mut indices: Vec<usize> = Coin::all().map(Coin::index).collect();
+    indices.sort_unstable();
+    assert_eq!(indices, (0..COIN_COUNT).collect::<Vec<_>>());
+    for coin in Coin::all() {
+        assert_eq!(Coin::from_index(coin.index()), Some(coin));
+    }
+    assert_eq!(Coin::from_index(COIN_COUNT), None);
+}
+
+#[test]
+fn primary_ids_are_the_lowest() {
+    for coin in Coin::all() {
+        assert!(coin.ids().is_sorted(), "{:?}", coin);
+        assert_eq!(coin.id(), coin.canonical_id(), "{:?}", coin);
+    }
+}
+
+#[test]
+fn all_has_a_coin_per_entry() {
+    let entries = full_registry()
+        .filter(|line| line.trim_start().starts_with('[') && line.contains("], "))
+        .count();
+
+    assert_eq!(Coin::ALL.len(), entries);
+    assert_eq!(Coin::all().collect::<Vec<_>>(), Coin::ALL);
+}
+
+#[test]
+fn coin_count() {
+    assert_eq!(COIN_COUNT, Coin::all().count());
+
+    let ids: [u32; COIN_COUNT] = core::array::from_fn(|i| Coin::ALL[i].id());
+    assert_eq!(ids[0], 0);
+}
+
+#[test]
+fn well_known_coins() {
+    let known = [
+        (0, "BTC", Coin::Bitcoin),
+        (2, "LTC", Coin::Litecoin),
+        (3, "DOGE", Coin::Dogecoin),
+        (5, "DASH", Coin::Dash),
+        (60, "ETH", Coin::Ethereum),
+        (61, "ETC", Coin::EthereumClassic),
+        (118, "ATOM", Coin::Atom),
+        (128, "XMR", Coin::Monero),
+        (133, "ZEC", Coin::Zcash),
+        (144, "XRP", Coin::XRP),
+        (145, "BCH", Coin::BitcoinCash),
+        (148, "XLM", Coin::StellarLumens),
+        (194, "EOS", Coin::EOS),
+        (195, "TRX", Coin::Tron),
+        (283, "ALGO", Coin::Algorand),
+        (354, "DOT", Coin::Polkadot),
+        (397, "NEAR", Coin::NEARProtocol),
+        (434, "KSM", Coin::Kusama),
+        (501, "SOL", Coin::Solana),
+        (1729, "XTZ", Coin::Tezos),
+        (1815, "ADA", Coin::Cardano),
+        (5757, "STX", Coin::Stacks),
+        (9000, "AVAX", Coin::Avalanche),
+    ];
+
+    for (id, symbol, coin) in known {
+        assert_eq!(Coin::from_id(id), Some(coin), "coin type {}", id);
+        assert_eq!(Coin::from_symbol(symbol), Some(coin), "symbol {}", symbol);
+    }
+}
+
+#[cfg(feature = "lazy-maps")]
+#[test]
+fn lazy_maps_match_scans() {
+    for &(id, _) in Coin::ID_INDEX {
+        let scanned = Coin::all().find(|coin| coin.ids().contains(&id));
+        assert_eq!(Coin::id_map().get(&id).copied(), scanned);
+    }
+    for coin in Coin::all() {
+        if let Some(symbol) = coin.symbol() {
+            assert_eq!(Coin::symbol_map().get(symbol), Some(&coin));
+        }
+    }
+}